@@ -0,0 +1,94 @@
+// criterion benchmarks of a single generation for every engine, across grid sizes
+// run with `cargo bench -p game-of-life-core`
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use game_of_life_core::continuous::ContinuousUniverse;
+use game_of_life_core::reaction_diffusion::ReactionDiffusion;
+use game_of_life_core::sand::{Material, SandUniverse};
+use game_of_life_core::random::Xorshift;
+use game_of_life_core::Universe;
+
+const SIZES: [u32; 3] = [64, 256, 512];
+
+fn life(c: &mut Criterion) {
+    let mut group = c.benchmark_group("life");
+    for &size in SIZES.iter() {
+        let mut universe = Universe::new(size, size);
+        universe.reseed(1);
+        universe.randomize();
+
+        group.throughput(Throughput::Elements((size * size) as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| black_box(&mut universe).tick(1))
+        });
+    }
+    group.finish();
+}
+
+fn continuous(c: &mut Criterion) {
+    let mut group = c.benchmark_group("smooth_conway");
+    for &size in SIZES.iter() {
+        let mut universe = ContinuousUniverse::new(size, size);
+        universe.randomize(&mut Xorshift::new(1));
+
+        group.throughput(Throughput::Elements((size * size) as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| black_box(&mut universe).tick())
+        });
+    }
+    group.finish();
+}
+
+fn lenia(c: &mut Criterion) {
+    // the convolution is much heavier than the other engines, keep grids small
+    let mut group = c.benchmark_group("lenia");
+    group.sample_size(10);
+    for &size in [64u32, 128].iter() {
+        let mut universe = ContinuousUniverse::new(size, size);
+        universe.set_lenia(13, 0.15, 0.015, 0.1);
+        universe.randomize(&mut Xorshift::new(1));
+
+        group.throughput(Throughput::Elements((size * size) as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| black_box(&mut universe).tick())
+        });
+    }
+    group.finish();
+}
+
+fn reaction_diffusion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gray_scott");
+    for &size in SIZES.iter() {
+        let mut simulation = ReactionDiffusion::new(size, size);
+        simulation.seed(size / 2, size / 2, size / 8);
+
+        group.throughput(Throughput::Elements((size * size) as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| black_box(&mut simulation).tick())
+        });
+    }
+    group.finish();
+}
+
+fn sand(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sand");
+    for &size in SIZES.iter() {
+        let mut sandbox = SandUniverse::new(size, size);
+        let mut rng = Xorshift::new(1);
+
+        group.throughput(Throughput::Elements((size * size) as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| {
+                // keep the sandbox busy by pouring in more sand every generation
+                sandbox.paint(0, size / 2, size / 16, Material::Sand);
+                black_box(&mut sandbox).tick(&mut rng)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, life, continuous, lenia, reaction_diffusion, sand);
+criterion_main!(benches);
@@ -0,0 +1,15 @@
+// compresses the pattern tables in data/ into OUT_DIR for the lexicon feature, see
+// src/lexicon.rs
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "lexicon")]
+    for table in ["lexicon.txt", "census.txt"] {
+        let path = format!("data/{}", table);
+        println!("cargo:rerun-if-changed={}", path);
+        let text = std::fs::read(&path).unwrap();
+        let compressed = miniz_oxide::deflate::compress_to_vec(&text, 10);
+        let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join(format!("{}.deflate", table));
+        std::fs::write(out, compressed).unwrap();
+    }
+}
@@ -0,0 +1,127 @@
+// optional age layer - how many generations in a row each cell has been alive - and a
+// palette to turn it into a ready-to-blit RGBA image
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::life_history::history_color;
+use crate::{Cell, Error, Universe};
+
+// colors are packed 0xRRGGBBAA
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Palette {
+    pub dead: u32,
+    pub wall: u32,
+    pub preview: u32, // drawn over the cells a pending placement would bring to life, see preview.rs
+    // LifeHistory mode, see life_history.rs: dead cells that were alive, and marked cells
+    pub envelope: u32,
+    pub marked_alive: u32,
+    pub marked_dead: u32,
+    // color of a cell alive for 1, 2, 3, ... generations, the last one is used for anything older
+    pub ages: Vec<u32>,
+}
+
+impl Default for Palette {
+    // white background, newborn cells bright yellow cooling through orange and red to a
+    // deep purple for long-lived still lifes
+    fn default() -> Palette {
+        Palette {
+            dead: 0xFFFFFFFF,
+            wall: 0x808080FF,
+            preview: 0x1E90FF80,
+            envelope: 0xCCE5FFFF,
+            marked_alive: 0x2E8B57FF,
+            marked_dead: 0xF4A460FF,
+            ages: vec![0xFFD700FF, 0xFFA500FF, 0xFF6347FF, 0xDC143CFF, 0xB22222FF, 0x8B008BFF, 0x4B0082FF],
+        }
+    }
+}
+
+impl Universe {
+    // ages cost an extra pass per generation, so they are only tracked on request - cells alive
+    // when tracking starts count as newborn
+    pub fn set_track_ages(&mut self, track: bool) {
+        self.ages = match track {
+            true => Some(self.cells.iter().map(|&cell| (cell == Cell::Alive) as u16).collect()),
+            false => None,
+        };
+    }
+
+    // generations each cell has been alive in a row (0 for dead cells), None when not tracked
+    // cells edited to alive since the last tick still read 0
+    pub fn ages(&self) -> Option<&[u16]> {
+        self.ages.as_deref()
+    }
+
+    // "aging" rule modifier: cells alive for max_age generations in a row die on the next one,
+    // whatever their neighbors say, so nothing ever settles down. turns age tracking on, and
+    // only applies while ages are tracked - 0 removes the limit
+    pub fn set_max_age(&mut self, max_age: u16) {
+        self.max_age = max_age;
+        if max_age > 0 && self.ages.is_none() {
+            self.set_track_ages(true);
+        }
+    }
+
+    pub fn max_age(&self) -> u16 {
+        self.max_age
+    }
+
+    // called after every generation
+    pub(crate) fn update_ages(&mut self) {
+        if let Some(ages) = &mut self.ages {
+            for (age, cell) in ages.iter_mut().zip(self.cells.iter_mut()) {
+                *age = match cell {
+                    Cell::Alive => age.saturating_add(1),
+                    Cell::Dead | Cell::Wall => 0,
+                };
+                if self.max_age > 0 && *age > self.max_age {
+                    *cell = Cell::Dead;
+                    *age = 0;
+                }
+            }
+        }
+    }
+
+    pub fn set_palette(&mut self, palette: Palette) -> Result<(), Error> {
+        if palette.ages.is_empty() {
+            return Err(Error::EmptyPalette);
+        }
+        self.palette = palette;
+        Ok(())
+    }
+
+    pub fn palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    // 4 bytes (R, G, B, A) per cell in row-major order, colored by age through the palette -
+    // without age tracking every live cell gets the first age color. the buffer is reused
+    // between calls
+    pub fn render_rgba_aged(&mut self) -> &[u8] {
+        self.rgba.resize(self.cells.len() * 4, 0);
+        let oldest = self.palette.ages.len() - 1;
+
+        for (idx, pixel) in self.rgba.chunks_exact_mut(4).enumerate() {
+            let color = match self.cells[idx] {
+                Cell::Dead => self.palette.dead,
+                Cell::Wall => self.palette.wall,
+                Cell::Alive => {
+                    let age = self.ages.as_ref().map_or(1, |ages| ages[idx].max(1)) as usize;
+                    self.palette.ages[(age - 1).min(oldest)]
+                }
+            };
+            let flags = self.life_history.as_ref().map_or(0, |history| history[idx]);
+            let color = history_color(&self.palette, self.cells[idx], flags).unwrap_or(color);
+            pixel.copy_from_slice(&color.to_be_bytes());
+        }
+        self.draw_preview();
+
+        &self.rgba
+    }
+
+    // the image from the last render_rgba_aged, possibly stale
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+}
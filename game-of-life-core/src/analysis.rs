@@ -0,0 +1,238 @@
+// measurements of the live population
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Cell, Error, Universe};
+
+// largest block accepted by block_entropy, so a block's pattern fits in a u32
+const MAX_BLOCK_SIZE: u32 = 5;
+
+// smallest rectangle containing every live cell, bounds inclusive
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min_row: u32,
+    pub min_col: u32,
+    pub max_row: u32,
+    pub max_col: u32,
+}
+
+impl BoundingBox {
+    pub fn width(&self) -> u32 {
+        self.max_col - self.min_col + 1
+    }
+
+    pub fn height(&self) -> u32 {
+        self.max_row - self.min_row + 1
+    }
+}
+
+impl Universe {
+    // bounding box of the live cells, None when everything is dead
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let mut bounds: Option<BoundingBox> = None;
+
+        for (idx, &cell) in self.cells.iter().enumerate() {
+            if cell != Cell::Alive {
+                continue;
+            }
+            let row = idx as u32 / self.width;
+            let col = idx as u32 % self.width;
+
+            bounds = Some(match bounds {
+                None => BoundingBox {
+                    min_row: row,
+                    min_col: col,
+                    max_row: row,
+                    max_col: col,
+                },
+                Some(b) => BoundingBox {
+                    // rows are visited in order, so min_row never changes after the first hit
+                    min_row: b.min_row,
+                    min_col: b.min_col.min(col),
+                    max_row: row,
+                    max_col: b.max_col.max(col),
+                },
+            });
+        }
+
+        bounds
+    }
+
+    // number of live cells
+    pub fn population(&self) -> usize {
+        self.cells.iter().filter(|&&cell| cell == Cell::Alive).count()
+    }
+
+    // fraction of cells that are alive, between 0 and 1
+    pub fn density(&self) -> f64 {
+        if self.cells.is_empty() {
+            return 0.0;
+        }
+        self.population() as f64 / self.cells.len() as f64
+    }
+
+    // shannon entropy (in bits) of the block_size x block_size patterns found when the grid is
+    // cut into non-overlapping blocks - 0 for a uniform grid, up to block_size^2 for noise.
+    // partial blocks along the right and bottom edges are ignored, walls count as dead
+    pub fn block_entropy(&self, block_size: u32) -> Result<f64, Error> {
+        if !(1..=MAX_BLOCK_SIZE).contains(&block_size) {
+            return Err(Error::BlockSize {
+                max: MAX_BLOCK_SIZE,
+                got: block_size,
+            });
+        }
+
+        // each block's pattern packed into an integer, one bit per cell
+        let mut counts: BTreeMap<u32, u32> = BTreeMap::new();
+        let mut blocks = 0;
+        for block_row in 0..self.height / block_size {
+            for block_col in 0..self.width / block_size {
+                let mut pattern = 0u32;
+                for row in 0..block_size {
+                    for col in 0..block_size {
+                        let idx = self.get_index(block_row * block_size + row, block_col * block_size + col);
+                        pattern = pattern << 1 | (self.cells[idx] == Cell::Alive) as u32;
+                    }
+                }
+                *counts.entry(pattern).or_insert(0) += 1;
+                blocks += 1;
+            }
+        }
+
+        if blocks == 0 {
+            return Ok(0.0);
+        }
+        Ok(counts
+            .values()
+            .map(|&count| {
+                let p = count as f64 / blocks as f64;
+                -p * libm::log2(p)
+            })
+            .sum())
+    }
+}
+
+// how far a pattern moves each time it repeats its shape
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Velocity {
+    pub period: u32,
+    pub dx: i32, // columns moved per period, positive to the right
+    pub dy: i32, // rows moved per period, positive downwards
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Stationary, // still life or oscillator
+    Orthogonal,
+    Diagonal,
+    Oblique,
+}
+
+impl Velocity {
+    pub fn is_spaceship(&self) -> bool {
+        self.dx != 0 || self.dy != 0
+    }
+
+    pub fn direction(&self) -> Direction {
+        match (self.dx.unsigned_abs(), self.dy.unsigned_abs()) {
+            (0, 0) => Direction::Stationary,
+            (0, _) | (_, 0) => Direction::Orthogonal,
+            (x, y) if x == y => Direction::Diagonal,
+            _ => Direction::Oblique,
+        }
+    }
+
+    // speed as a reduced fraction of c (one cell per generation), measured along the
+    // larger axis the way spaceship speeds are usually quoted - the glider is (1, 4)
+    pub fn speed(&self) -> (u32, u32) {
+        let distance = self.dx.unsigned_abs().max(self.dy.unsigned_abs());
+        let divisor = gcd(distance, self.period);
+        (distance / divisor, self.period / divisor)
+    }
+
+    // human readable classification, e.g. "c/4 diagonal", "2c/5 orthogonal", "oscillator p3"
+    pub fn label(&self) -> String {
+        if !self.is_spaceship() {
+            return if self.period == 1 {
+                String::from("still life")
+            } else {
+                format!("oscillator p{}", self.period)
+            };
+        }
+
+        let (numerator, denominator) = self.speed();
+        let speed = match (numerator, denominator) {
+            (1, 1) => String::from("c"),
+            (1, d) => format!("c/{}", d),
+            (n, 1) => format!("{}c", n),
+            (n, d) => format!("{}c/{}", n, d),
+        };
+        let direction = match self.direction() {
+            Direction::Orthogonal => "orthogonal",
+            Direction::Diagonal => "diagonal",
+            _ => "oblique",
+        };
+        format!("{} {}", speed, direction)
+    }
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// signed displacement from one coordinate to another, taking the short way around when the
+// axis wraps so a spaceship crossing the edge doesn't look like it jumped across the grid
+fn displacement(from: u32, to: u32, size: u32, wrap: bool) -> i32 {
+    let delta = to as i32 - from as i32;
+    if !wrap {
+        return delta;
+    }
+    let size = size as i32;
+    let delta = delta.rem_euclid(size);
+    if delta > size / 2 { delta - size } else { delta }
+}
+
+impl Universe {
+    // live cells as offsets inside their bounding box, so two populations can be compared
+    // regardless of where they sit on the grid
+    fn shape(&self) -> Option<(BoundingBox, Vec<u32>)> {
+        let bounds = self.bounding_box()?;
+        let mut offsets = Vec::new();
+        for row in bounds.min_row..=bounds.max_row {
+            for col in bounds.min_col..=bounds.max_col {
+                if self.cells[self.get_index(row, col)] == Cell::Alive {
+                    offsets.push((row - bounds.min_row) * bounds.width() + (col - bounds.min_col));
+                }
+            }
+        }
+        Some((bounds, offsets))
+    }
+
+    // run a copy of the universe for up to max_period generations, tracking the bounding box
+    // of the population, and report the first generation where the current shape reappears
+    // along with how far it moved - None when the population dies out or never repeats
+    pub fn measure_velocity(&self, max_period: u32) -> Option<Velocity> {
+        let (start_bounds, start_shape) = self.shape()?;
+        let mut universe = self.clone();
+
+        for period in 1..=max_period {
+            universe.tick(1);
+            let (bounds, shape) = universe.shape()?;
+            if bounds.width() == start_bounds.width()
+                && bounds.height() == start_bounds.height()
+                && shape == start_shape
+            {
+                return Some(Velocity {
+                    period,
+                    dx: displacement(start_bounds.min_col, bounds.min_col, self.width, self.wrap_x),
+                    dy: displacement(start_bounds.min_row, bounds.min_row, self.height, self.wrap_y),
+                });
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,115 @@
+// apgcodes, the object names used by Catagolue (https://catagolue.hatsya.com), so objects found
+// here can be looked up in the soup search database
+//
+// a code is a prefix - xs<population> for still lifes, xp<period> for oscillators and
+// xq<period> for spaceships - and the extended Wechsler format of the object, taken over every
+// phase and orientation with the shortest (then alphabetically first) one winning, e.g. the
+// block is xs4_33 and the glider xq4_153
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::pattern::Pattern;
+use crate::rules::RuleTable;
+use crate::Universe;
+
+// each character of the extended Wechsler format is one column of a 5 row strip
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const STRIP_HEIGHT: u32 = 5;
+
+impl Pattern {
+    // extended Wechsler format of this exact phase and orientation: 5 row strips separated by
+    // 'z', one base 32 digit per column (bit 0 is the strip's top row), trailing blank
+    // columns dropped and runs of blank columns shortened to w (2), x (3) or y0..yz (4 to 39)
+    pub fn extended_wechsler(&self) -> String {
+        let strips = self.height().div_ceil(STRIP_HEIGHT);
+        let mut columns = alloc::vec![0u8; (strips * self.width()) as usize];
+        for &(row, col) in self.cells() {
+            columns[((row / STRIP_HEIGHT) * self.width() + col) as usize] |= 1 << (row % STRIP_HEIGHT);
+        }
+
+        let mut code = String::new();
+        for (strip, columns) in columns.chunks(self.width().max(1) as usize).enumerate() {
+            if strip > 0 {
+                code.push('z');
+            }
+            let used = columns.iter().rposition(|&column| column != 0).map_or(0, |last| last + 1);
+            let mut blanks = 0;
+            for &column in &columns[..used] {
+                if column == 0 {
+                    blanks += 1;
+                    continue;
+                }
+                push_blanks(&mut code, blanks);
+                blanks = 0;
+                code.push(DIGITS[column as usize] as char);
+            }
+        }
+        code
+    }
+
+    // the apgcode of this object evolving on its own under a rule, None if it doesn't come
+    // back to its starting shape within max_period generations (dies, grows, or is really
+    // several objects that drift apart)
+    pub fn apgcode(&self, rule: &RuleTable, max_period: u32) -> Option<String> {
+        if self.population() == 0 {
+            return None;
+        }
+
+        // big enough that nothing moving at up to c can reach the edge
+        let margin = max_period + 2;
+        let mut universe = Universe::new(self.width() + 2 * margin, self.height() + 2 * margin);
+        universe.set_wrap(false, false);
+        universe.set_rule_table(rule).unwrap();
+        let cells: Vec<(u32, u32)> = self.cells().iter().map(|&(row, col)| (row + margin, col + margin)).collect();
+        universe.set_cells(&cells);
+        let start = universe.bounding_box();
+
+        let mut phases = alloc::vec![self.clone()];
+        for period in 1..=max_period {
+            universe.tick(1);
+            let phase = Pattern::from_universe(&universe);
+            if phase != *self {
+                phases.push(phase);
+                continue;
+            }
+
+            let prefix = match (period, universe.bounding_box() == start) {
+                (1, _) => format!("xs{}", self.population()),
+                (_, true) => format!("xp{}", period),
+                (_, false) => format!("xq{}", period),
+            };
+            let code = phases
+                .iter()
+                .flat_map(|phase| phase.orientations())
+                .map(|orientation| orientation.extended_wechsler())
+                .min_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)))
+                .unwrap();
+            return Some(format!("{}_{}", prefix, code));
+        }
+        None
+    }
+}
+
+impl Universe {
+    // apgcode of the whole live population under the universe's own rule, see Pattern::apgcode
+    pub fn apgcode(&self, max_period: u32) -> Option<String> {
+        Pattern::from_universe(self).apgcode(self.rule_table(), max_period)
+    }
+}
+
+fn push_blanks(code: &mut String, mut blanks: usize) {
+    while blanks >= 4 {
+        let run = blanks.min(39);
+        code.push('y');
+        code.push(DIGITS[run - 4] as char);
+        blanks -= run;
+    }
+    match blanks {
+        3 => code.push('x'),
+        2 => code.push('w'),
+        1 => code.push('0'),
+        _ => {}
+    }
+}
@@ -0,0 +1,67 @@
+// cell-by-cell boolean operations between two universes, e.g. to compose a pattern from
+// several sources or compare two runs
+//
+// other is laid over this universe with its top-left at (row_offset, col_offset) - there is
+// no need for the sizes to match, anything outside other counts as dead and whatever of other
+// falls outside this universe is ignored. only live cells count as set, walls are kept
+
+use alloc::vec::Vec;
+
+use crate::{Cell, Universe};
+
+impl Universe {
+    // alive where either is
+    pub fn union(&mut self, other: &Universe, row_offset: i64, col_offset: i64) {
+        self.combine(other, row_offset, col_offset, |a, b| a || b);
+    }
+
+    // alive where both are
+    pub fn intersect(&mut self, other: &Universe, row_offset: i64, col_offset: i64) {
+        self.combine(other, row_offset, col_offset, |a, b| a && b);
+    }
+
+    // alive where exactly one is
+    pub fn xor(&mut self, other: &Universe, row_offset: i64, col_offset: i64) {
+        self.combine(other, row_offset, col_offset, |a, b| a != b);
+    }
+
+    // (row, col) of every cell of this universe that is alive in one and not the other, in
+    // row-major order
+    pub fn diff_cells(&self, other: &Universe, row_offset: i64, col_offset: i64) -> Vec<(u32, u32)> {
+        let mut diff = Vec::new();
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let alive = self.cells[self.get_index(row, col)] == Cell::Alive;
+                if alive != other.alive_at(row as i64 - row_offset, col as i64 - col_offset) {
+                    diff.push((row, col));
+                }
+            }
+        }
+        diff
+    }
+
+    fn combine(&mut self, other: &Universe, row_offset: i64, col_offset: i64, op: impl Fn(bool, bool) -> bool) {
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let idx = self.get_index(row, col);
+                if self.cells[idx] == Cell::Wall {
+                    continue;
+                }
+                let alive = op(
+                    self.cells[idx] == Cell::Alive,
+                    other.alive_at(row as i64 - row_offset, col as i64 - col_offset),
+                );
+                self.cells[idx] = if alive { Cell::Alive } else { Cell::Dead };
+            }
+        }
+    }
+
+    // whether a cell is alive, false outside the universe
+    fn alive_at(&self, row: i64, col: i64) -> bool {
+        row >= 0
+            && col >= 0
+            && row < self.height as i64
+            && col < self.width as i64
+            && self.cells[self.get_index(row as u32, col as u32)] == Cell::Alive
+    }
+}
@@ -0,0 +1,45 @@
+// coordinate checking for editing calls that take untrusted input, e.g. canvas clicks
+
+use crate::{Cell, Error, Universe};
+
+// what the checked editing calls do with coordinates past the edge
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strictness {
+    Clamp,  // snap to the nearest cell on the edge
+    Strict, // reject with Error::OutOfBounds
+}
+
+impl Universe {
+    pub fn set_strictness(&mut self, strictness: Strictness) {
+        self.strictness = strictness;
+    }
+
+    pub fn strictness(&self) -> Strictness {
+        self.strictness
+    }
+
+    // index of (row, col) after applying the strictness setting, unlike get_index this never
+    // lands in the wrong row or past the end of the buffer
+    pub fn checked_index(&self, row: u32, col: u32) -> Result<usize, Error> {
+        let in_bounds = row < self.height && col < self.width;
+        match self.strictness {
+            _ if in_bounds => Ok(self.get_index(row, col)),
+            Strictness::Clamp if self.width > 0 && self.height > 0 => {
+                Ok(self.get_index(row.min(self.height - 1), col.min(self.width - 1)))
+            }
+            _ => Err(Error::OutOfBounds { row, col }),
+        }
+    }
+
+    pub fn toggle_cell_checked(&mut self, row: u32, col: u32) -> Result<(), Error> {
+        let idx = self.checked_index(row, col)? as u32;
+        self.toggle_cell(idx / self.width, idx % self.width);
+        Ok(())
+    }
+
+    pub fn set_cell_checked(&mut self, row: u32, col: u32, state: Cell) -> Result<(), Error> {
+        let idx = self.checked_index(row, col)? as u32;
+        self.set_cell(idx / self.width, idx % self.width, state);
+        Ok(())
+    }
+}
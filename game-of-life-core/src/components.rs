@@ -0,0 +1,70 @@
+// connected groups of live cells, e.g. to color colonies apart or count distinct objects
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Cell, Universe};
+
+// the result of label_components
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Components {
+    labels: Vec<u32>, // per cell in row-major order, 0 for cells that aren't alive
+    sizes: Vec<usize>,
+}
+
+impl Components {
+    // component of every cell, numbered from 1 in row-major order of their first cell, 0 for
+    // cells that aren't alive
+    pub fn labels(&self) -> &[u32] {
+        &self.labels
+    }
+
+    // live cells in each component, the size of component id is sizes()[id - 1]
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+
+    pub fn count(&self) -> usize {
+        self.sizes.len()
+    }
+}
+
+impl Universe {
+    // group live cells that touch, diagonals included, across the edges that wrap
+    pub fn label_components(&self) -> Components {
+        let mut labels = vec![0; self.cells.len()];
+        let mut sizes = Vec::new();
+        let mut stack = Vec::new();
+
+        for start in 0..self.cells.len() {
+            if self.cells[start] != Cell::Alive || labels[start] != 0 {
+                continue;
+            }
+
+            let label = sizes.len() as u32 + 1;
+            let mut size = 0;
+            labels[start] = label;
+            stack.push(start);
+            while let Some(idx) = stack.pop() {
+                size += 1;
+                let (row, col) = (idx as u32 / self.width, idx as u32 % self.width);
+                for delta_row in -1..=1 {
+                    for delta_col in -1..=1 {
+                        let neighbor = self
+                            .neighbor(row, col, delta_row, delta_col)
+                            .map(|(row, col)| self.get_index(row, col));
+                        if let Some(neighbor) = neighbor {
+                            if self.cells[neighbor] == Cell::Alive && labels[neighbor] == 0 {
+                                labels[neighbor] = label;
+                                stack.push(neighbor);
+                            }
+                        }
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+
+        Components { labels, sizes }
+    }
+}
@@ -0,0 +1,69 @@
+// cross-backend conformance: the steppers (tick's sliding window, tick_partial's slices and
+// the reference oracle) have to agree cell for cell, or switching between them for speed
+// would change the results
+//
+// they share the rule tables and the B0 emulation, but each finds its neighbors its own way,
+// so the edges (wrapping, twists, cells past a non-wrapping edge, the B0 background) are
+// where they could part ways
+
+use core::fmt;
+
+use crate::Universe;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    TickPartial, // tick_partial a row at a time
+    Reference,   // reference_step, see reference.rs
+}
+
+// a backend that disagreed with tick
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Disagreement {
+    pub backend: Backend,
+    pub generation: usize, // 1 is the first generation after the starting state
+    pub row: u32,
+    pub col: u32,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} disagreed with Tick at generation {}, cell ({}, {})",
+            self.backend, self.generation, self.row, self.col
+        )
+    }
+}
+
+impl core::error::Error for Disagreement {}
+
+impl Universe {
+    // step a copy of the universe with every backend for some generations, starting from the
+    // current state (which is left untouched), and report the first backend to disagree with
+    // tick. injections aren't part of the rules and are left out
+    pub fn assert_backends_agree(&self, generations: usize) -> Result<(), Disagreement> {
+        let mut tick = self.clone();
+        tick.injector = None;
+        let mut partial = tick.clone();
+        let mut reference = tick.clone();
+
+        for generation in 1..=generations {
+            tick.tick(1);
+            while !partial.tick_partial(1) {}
+            reference.reference_tick();
+
+            for (backend, universe) in [(Backend::TickPartial, &partial), (Backend::Reference, &reference)] {
+                if let Some(idx) = tick.cells.iter().zip(&universe.cells).position(|(a, b)| a != b) {
+                    return Err(Disagreement {
+                        backend,
+                        generation,
+                        row: idx as u32 / self.width,
+                        col: idx as u32 % self.width,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::random::Rng;
+
+use crate::lenia::Lenia;
+
+// a grid of f32 cells, the substrate for continuous automata (SmoothLife and friends)
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatGrid {
+    width: u32,
+    height: u32,
+    values: Vec<f32>,
+}
+
+impl FloatGrid {
+    pub fn new(width: u32, height: u32) -> FloatGrid {
+        FloatGrid {
+            width,
+            height,
+            values: vec![0.0; (width * height) as usize],
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    pub fn values_mut(&mut self) -> &mut [f32] {
+        &mut self.values
+    }
+
+    // translate a 2d coordinate into a 1d index
+    pub fn get_index(&self, row: u32, column: u32) -> usize {
+        (row * self.width + column) as usize
+    }
+
+    // value at an offset from (row, column), wrapping around the edges (torus)
+    pub fn get_wrapped(&self, row: u32, column: u32, delta_row: i32, delta_col: i32) -> f32 {
+        let neighbor_row = (row as i64 + delta_row as i64).rem_euclid(self.height as i64) as u32;
+        let neighbor_col = (column as i64 + delta_col as i64).rem_euclid(self.width as i64) as u32;
+        self.values[self.get_index(neighbor_row, neighbor_col)]
+    }
+}
+
+// computes the next value of a single cell from the current grid
+pub trait Kernel {
+    fn next_value(&self, grid: &FloatGrid, row: u32, column: u32) -> f32;
+}
+
+// a continuous take on Conway's rules: the neighborhood sum is compared against smoothed
+// birth/survival intervals and the cell's own value blends between the two
+pub struct SmoothConway;
+
+impl Kernel for SmoothConway {
+    fn next_value(&self, grid: &FloatGrid, row: u32, column: u32) -> f32 {
+        let mut sum = 0.0;
+        for delta_row in -1..=1 {
+            for delta_col in -1..=1 {
+                if delta_row == 0 && delta_col == 0 {
+                    continue;
+                }
+                sum += grid.get_wrapped(row, column, delta_row, delta_col);
+            }
+        }
+
+        let cell = grid.get_wrapped(row, column, 0, 0);
+        let alive = interval(sum, 2.0, 3.5);
+        let born = interval(sum, 2.5, 3.5);
+        cell * alive + (1.0 - cell) * born
+    }
+}
+
+// smooth step in and out of [low, high], 0.5 wide on each side
+fn interval(x: f32, low: f32, high: f32) -> f32 {
+    let rise = ((x - low) / 0.5 + 0.5).clamp(0.0, 1.0);
+    let fall = ((high - x) / 0.5 + 0.5).clamp(0.0, 1.0);
+    rise.min(fall)
+}
+
+// built-in kernels
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EngineMode {
+    SmoothConway = 0,
+    Lenia = 1,
+}
+
+pub struct ContinuousUniverse {
+    grid: FloatGrid,
+    kernel: Box<dyn Kernel>,
+}
+
+impl ContinuousUniverse {
+    pub fn new(width: u32, height: u32) -> ContinuousUniverse {
+        ContinuousUniverse::with_kernel(width, height, Box::new(SmoothConway))
+    }
+
+    pub fn width(&self) -> u32 {
+        self.grid.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.grid.height
+    }
+
+    pub fn values(&self) -> &[f32] {
+        &self.grid.values
+    }
+
+    pub fn get_value(&self, row: u32, col: u32) -> f32 {
+        self.grid.values[self.grid.get_index(row, col)]
+    }
+
+    pub fn set_value(&mut self, row: u32, col: u32, value: f32) {
+        let idx = self.grid.get_index(row, col);
+        self.grid.values[idx] = value;
+    }
+
+    // switch to one of the built-in kernels with its default parameters
+    pub fn set_mode(&mut self, mode: EngineMode) {
+        self.kernel = match mode {
+            EngineMode::SmoothConway => Box::new(SmoothConway),
+            EngineMode::Lenia => Box::new(Lenia::default()),
+        };
+    }
+
+    // switch to Lenia with a custom kernel radius, growth function (mu, sigma) and time step
+    pub fn set_lenia(&mut self, radius: u32, mu: f32, sigma: f32, dt: f32) {
+        self.kernel = Box::new(Lenia::new(radius, mu, sigma, dt));
+    }
+
+    // fill the grid with uniform random values in [0, 1)
+    pub fn randomize<R: Rng>(&mut self, rng: &mut R) {
+        for value in self.grid.values.iter_mut() {
+            *value = rng.gen_f32();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        for value in self.grid.values.iter_mut() {
+            *value = 0.0;
+        }
+    }
+
+    // apply the kernel to every cell (new generation)
+    pub fn tick(&mut self) {
+        let mut next = Vec::with_capacity(self.grid.values.len());
+
+        for row in 0..self.grid.height {
+            for col in 0..self.grid.width {
+                next.push(self.kernel.next_value(&self.grid, row, col));
+            }
+        }
+
+        self.grid.values = next;
+    }
+
+    pub fn with_kernel(width: u32, height: u32, kernel: Box<dyn Kernel>) -> ContinuousUniverse {
+        ContinuousUniverse {
+            grid: FloatGrid::new(width, height),
+            kernel,
+        }
+    }
+
+    pub fn set_kernel(&mut self, kernel: Box<dyn Kernel>) {
+        self.kernel = kernel;
+    }
+
+    pub fn grid(&self) -> &FloatGrid {
+        &self.grid
+    }
+
+    pub fn grid_mut(&mut self) -> &mut FloatGrid {
+        &mut self.grid
+    }
+}
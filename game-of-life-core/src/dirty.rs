@@ -0,0 +1,104 @@
+// which parts of the grid changed during a tick, coalesced into a few rectangles so
+// frontends can redraw only those instead of the whole canvas
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Cell, Universe};
+
+// changes are tracked per TILE x TILE block of cells
+const TILE: u32 = 16;
+// past this many rectangles a single one covering all of them is cheaper to draw
+const MAX_RECTS: usize = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rect {
+    pub row: u32,
+    pub col: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+// rectangles covering every cell that differs between before and after (same dimensions)
+pub(crate) fn coalesce(before: &[Cell], after: &[Cell], width: u32, height: u32) -> Vec<Rect> {
+    let tiles_x = width.div_ceil(TILE);
+    let tiles_y = height.div_ceil(TILE);
+
+    let mut dirty = vec![false; (tiles_x * tiles_y) as usize];
+    for (idx, (old, new)) in before.iter().zip(after).enumerate() {
+        if old != new {
+            let row = idx as u32 / width;
+            let col = idx as u32 % width;
+            dirty[((row / TILE) * tiles_x + col / TILE) as usize] = true;
+        }
+    }
+
+    // horizontal runs of dirty tiles, stacked into one rectangle while consecutive tile
+    // rows have a run with exactly the same span - rects are in tiles until the end
+    let mut rects: Vec<Rect> = Vec::new();
+    // runs of the previous tile row, with the rect they extended
+    let mut open: Vec<(u32, u32, usize)> = Vec::new();
+    for tile_row in 0..tiles_y {
+        let mut next_open = Vec::new();
+        let mut tile_col = 0;
+        while tile_col < tiles_x {
+            if !dirty[(tile_row * tiles_x + tile_col) as usize] {
+                tile_col += 1;
+                continue;
+            }
+            let start = tile_col;
+            while tile_col < tiles_x && dirty[(tile_row * tiles_x + tile_col) as usize] {
+                tile_col += 1;
+            }
+
+            let rect_idx = match open.iter().find(|&&(s, e, _)| s == start && e == tile_col) {
+                Some(&(_, _, rect_idx)) => {
+                    rects[rect_idx].height += 1;
+                    rect_idx
+                }
+                None => {
+                    rects.push(Rect {
+                        row: tile_row,
+                        col: start,
+                        width: tile_col - start,
+                        height: 1,
+                    });
+                    rects.len() - 1
+                }
+            };
+            next_open.push((start, tile_col, rect_idx));
+        }
+        open = next_open;
+    }
+
+    if rects.len() > MAX_RECTS {
+        let row = rects.iter().map(|r| r.row).min().unwrap();
+        let col = rects.iter().map(|r| r.col).min().unwrap();
+        let bottom = rects.iter().map(|r| r.row + r.height).max().unwrap();
+        let right = rects.iter().map(|r| r.col + r.width).max().unwrap();
+        rects = vec![Rect {
+            row,
+            col,
+            width: right - col,
+            height: bottom - row,
+        }];
+    }
+
+    // tiles to cells, the last tile row and column may stick out past the grid
+    rects
+        .into_iter()
+        .map(|r| Rect {
+            row: r.row * TILE,
+            col: r.col * TILE,
+            width: (r.width * TILE).min(width - r.col * TILE),
+            height: (r.height * TILE).min(height - r.row * TILE),
+        })
+        .collect()
+}
+
+impl Universe {
+    // areas that changed during the last tick or advance, edits made in between aren't tracked
+    pub fn dirty_rects(&self) -> &[Rect] {
+        &self.dirty
+    }
+}
@@ -0,0 +1,101 @@
+// drawing tools on top of set_cell, all mirrored by the edit symmetry
+
+use alloc::vec::Vec;
+
+use crate::pattern::Pattern;
+use crate::symmetry::Symmetry;
+use crate::{Cell, Universe};
+
+impl Universe {
+    // mirror every edit (toggle_cell, set_cell, paint, draw_line, stamp, ...) across the
+    // universe so hand-drawn starting configurations stay symmetric
+    pub fn set_edit_symmetry(&mut self, symmetry: Symmetry) {
+        self.edit_symmetry = symmetry;
+    }
+
+    pub fn edit_symmetry(&self) -> Symmetry {
+        self.edit_symmetry
+    }
+
+    // indices of (row, col) and its mirror images under the edit symmetry, each only once
+    // coordinates past the edge aren't mirrored, they index the buffer as they always did
+    pub(crate) fn edit_targets(&self, row: u32, col: u32) -> Vec<usize> {
+        if row >= self.height || col >= self.width {
+            return alloc::vec![self.get_index(row, col)];
+        }
+        let mut targets: Vec<usize> = self
+            .edit_symmetry
+            .images(row, col, self.width, self.height)
+            .into_iter()
+            .map(|(row, col)| self.get_index(row, col))
+            .collect();
+        targets.sort_unstable();
+        targets.dedup();
+        targets
+    }
+
+    // fill a disc of the given radius around (row, col), wrapped or clipped like stamp
+    pub fn paint(&mut self, row: u32, col: u32, radius: u32, state: Cell) {
+        let radius = radius as i64;
+        for dr in -radius..=radius {
+            for dc in -radius..=radius {
+                if dr * dr + dc * dc > radius * radius {
+                    continue;
+                }
+                if let Some((row, col)) = self.offset(row, col, dr, dc) {
+                    self.set_cell(row, col, state);
+                }
+            }
+        }
+    }
+
+    // straight line of cells from (row0, col0) to (row1, col1), ends included, e.g. to join
+    // up the positions of a fast pointer drag
+    pub fn draw_line(&mut self, row0: u32, col0: u32, row1: u32, col1: u32, state: Cell) {
+        // bresenham, stepping along both axes with an error term
+        let (mut row, mut col) = (row0 as i64, col0 as i64);
+        let (row1, col1) = (row1 as i64, col1 as i64);
+        let d_row = -(row1 - row).abs();
+        let d_col = (col1 - col).abs();
+        let step_row = if row < row1 { 1 } else { -1 };
+        let step_col = if col < col1 { 1 } else { -1 };
+        let mut error = d_col + d_row;
+
+        loop {
+            if row < self.height as i64 && col < self.width as i64 {
+                self.set_cell(row as u32, col as u32, state);
+            }
+            if row == row1 && col == col1 {
+                break;
+            }
+            let doubled = 2 * error;
+            if doubled >= d_row {
+                error += d_row;
+                col += step_col;
+            }
+            if doubled <= d_col {
+                error += d_col;
+                row += step_row;
+            }
+        }
+    }
+
+    // bring the live cells of pattern to life with its top-left corner at (row, col), wrapping
+    // around the edges that wrap and clipped at the others
+    pub fn stamp(&mut self, pattern: &Pattern, row: u32, col: u32) {
+        for &(dr, dc) in pattern.cells() {
+            if let Some((row, col)) = self.offset(row, col, dr as i64, dc as i64) {
+                self.set_cell(row, col, Cell::Alive);
+            }
+        }
+    }
+
+    // (row + dr, col + dc), wrapped (and twisted) the way the rules see it, None past an edge
+    // that doesn't wrap
+    pub(crate) fn offset(&self, row: u32, col: u32, dr: i64, dc: i64) -> Option<(u32, u32)> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        self.neighbor(row, col, dr, dc)
+    }
+}
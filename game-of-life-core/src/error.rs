@@ -0,0 +1,66 @@
+// everything that can go wrong in the engine's fallible APIs
+
+use alloc::string::String;
+use core::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    // a rulestring that isn't of the B<counts>/S<counts> form
+    InvalidRule(String),
+    // a neighbor count outside 0-8 inside an otherwise well-formed rulestring
+    InvalidNeighborCount { rule: String, count: char },
+    RuleTableSize { expected: usize, got: usize },
+    // a flat coordinate list with a row but no column at the end
+    UnpairedCoordinates,
+    // a flat cell array whose length isn't width * height
+    CellCountMismatch { expected: usize, got: usize },
+    // a character in an ASCII art pattern that is neither the live nor the dead character, or
+    // one RLE has no meaning for
+    UnexpectedChar { c: char, row: u32, col: u32 },
+    SnapshotTruncated,
+    // not a full snapshot, or one from a newer version
+    UnknownSnapshotFormat,
+    SnapshotSizeMismatch,
+    BlockSize { max: u32, got: u32 },
+    // a palette without any colors for live cells
+    EmptyPalette,
+    // coordinates past the edge of the universe, see bounds.rs
+    OutOfBounds { row: u32, col: u32 },
+    // RGBA data whose length isn't width * height * 4
+    ImageSize { expected: usize, got: usize },
+    // insert_named with a name the lexicon doesn't have
+    UnknownPattern(String),
+    // a twisted torus shifted along both axes at once, see twist.rs
+    DoubleTwist,
+    // a grid read from text whose size overflows or is past the limit, see rle::MAX_CELLS
+    TooLarge { width: u32, height: u32 },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidRule(rule) => write!(f, "invalid rule '{}', expected B<counts>/S<counts>", rule),
+            Error::InvalidNeighborCount { rule, count } => {
+                write!(f, "invalid neighbor count '{}' in rule '{}'", count, rule)
+            }
+            Error::RuleTableSize { expected, got } => write!(f, "rule table must have {} entries, got {}", expected, got),
+            Error::UnpairedCoordinates => write!(f, "coordinates must come in row/col pairs"),
+            Error::CellCountMismatch { expected, got } => write!(f, "expected {} cells, got {}", expected, got),
+            Error::UnexpectedChar { c, row, col } => {
+                write!(f, "unexpected character '{}' at row {}, column {}", c, row, col)
+            }
+            Error::SnapshotTruncated => write!(f, "snapshot is missing its header"),
+            Error::UnknownSnapshotFormat => write!(f, "not a snapshot this version can read"),
+            Error::SnapshotSizeMismatch => write!(f, "snapshot size does not match its dimensions"),
+            Error::BlockSize { max, got } => write!(f, "block size must be between 1 and {}, got {}", max, got),
+            Error::EmptyPalette => write!(f, "palette needs at least one color for live cells"),
+            Error::OutOfBounds { row, col } => write!(f, "cell ({}, {}) is outside the universe", row, col),
+            Error::ImageSize { expected, got } => write!(f, "expected {} bytes of RGBA data, got {}", expected, got),
+            Error::UnknownPattern(name) => write!(f, "no pattern named {:?} in the lexicon", name),
+            Error::DoubleTwist => write!(f, "only one of the row and column shifts can be non-zero"),
+            Error::TooLarge { width, height } => write!(f, "a {}x{} grid is too large", width, height),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
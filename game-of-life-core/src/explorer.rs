@@ -0,0 +1,79 @@
+// headless search through random B/S rules for ones that do something interesting
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::random::Rng;
+use crate::rules::{self, RuleTable};
+use crate::{Cell, Universe};
+
+// chance of each neighbor count being part of a random rule's birth or survival set
+const COUNT_PROBABILITY: f64 = 0.3;
+
+// how one rule behaved on the test soup, every measure is 0-1
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleScore {
+    pub rule: String,
+    pub activity: f64, // fraction of cells changing state per generation, over the second half of the run
+    pub growth: f64,   // final density of live cells
+    pub entropy: f64,  // 2x2 block entropy of the final state, scaled down from bits to 0-1
+    pub score: f64,    // activity * entropy * (1 - growth), high for busy but structured rules
+}
+
+// random B/S birth and survival counts - birth on 0 neighbors is left out since the whole
+// plane would flash on and off
+fn random_rule<R: Rng>(rng: &mut R) -> (Vec<u8>, Vec<u8>) {
+    let birth = (1..=8).filter(|_| rng.gen_bool(COUNT_PROBABILITY)).collect();
+    let survival = (0..=8).filter(|_| rng.gen_bool(COUNT_PROBABILITY)).collect();
+    (birth, survival)
+}
+
+// run soup under table for generations and measure it
+fn score(soup: &Universe, rule: String, table: RuleTable, generations: u32) -> RuleScore {
+    let mut universe = soup.clone();
+    // the table comes straight from totalistic_table, so it has the right size
+    universe.set_rule_table(&table).unwrap();
+
+    let cells = universe.get_cells().len().max(1) as f64;
+    let mut changes = 0;
+    let mut measured = 0;
+    for generation in 0..generations {
+        let before = universe.get_cells().to_vec();
+        universe.tick(1);
+        // early generations mostly show the soup dissolving, measure the settled behavior
+        if generation >= generations / 2 {
+            changes += before.iter().zip(universe.get_cells()).filter(|(a, b)| a != b).count();
+            measured += 1;
+        }
+    }
+
+    let activity = if measured == 0 { 0.0 } else { changes as f64 / (cells * measured as f64) };
+    let growth = universe.get_cells().iter().filter(|&&cell| cell == Cell::Alive).count() as f64 / cells;
+    // 2x2 blocks carry at most 4 bits
+    let entropy = universe.block_entropy(2).unwrap_or(0.0) / 4.0;
+    RuleScore {
+        rule,
+        activity,
+        growth,
+        entropy,
+        score: activity * entropy * (1.0 - growth),
+    }
+}
+
+// try count random rules on the same width x height soup (so they are compared fairly) for
+// generations each, best score first
+pub fn explore_rules<R: Rng>(count: usize, width: u32, height: u32, generations: u32, rng: &mut R) -> Vec<RuleScore> {
+    let mut soup = Universe::new(width, height);
+    soup.reseed(rng.next_u64());
+    soup.randomize();
+
+    let mut scores: Vec<RuleScore> = (0..count)
+        .map(|_| {
+            let (birth, survival) = random_rule(rng);
+            let table = rules::totalistic_table(&birth, &survival);
+            score(&soup, rules::rule_string(&birth, &survival), table, generations)
+        })
+        .collect();
+    scores.sort_by(|a, b| b.score.total_cmp(&a.score));
+    scores
+}
@@ -0,0 +1,81 @@
+// long exposure images: how often each cell was alive over a run, so glider trails and the
+// busy parts of a soup show up in a single picture
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::age::Palette;
+use crate::{Cell, Error, Universe};
+
+impl Universe {
+    // start (or stop) summing up live cells every generation, starting from an empty exposure
+    pub fn set_track_exposure(&mut self, track: bool) {
+        self.exposure = match track {
+            true => Some(vec![0; self.cells.len()]),
+            false => None,
+        };
+        self.exposed_generations = 0;
+    }
+
+    // generations each cell has been alive since tracking started, None when not tracked
+    pub fn exposure(&self) -> Option<&[u32]> {
+        self.exposure.as_deref()
+    }
+
+    // generations summed up in the exposure
+    pub fn exposed_generations(&self) -> u32 {
+        self.exposed_generations
+    }
+
+    // called after every generation
+    pub(crate) fn update_exposure(&mut self) {
+        if let Some(exposure) = &mut self.exposure {
+            for (count, &cell) in exposure.iter_mut().zip(&self.cells) {
+                *count = count.saturating_add((cell == Cell::Alive) as u32);
+            }
+            self.exposed_generations = self.exposed_generations.saturating_add(1);
+        }
+    }
+
+    // one float per cell in row-major order, the share of the exposed generations it spent
+    // alive (0 to 1) - all 0 without tracking. the buffer is reused between calls
+    pub fn render_heatmap(&mut self) -> &[f32] {
+        let generations = self.exposed_generations.max(1) as f32;
+        self.heatmap.clear();
+        match &self.exposure {
+            Some(exposure) => self.heatmap.extend(exposure.iter().map(|&count| count as f32 / generations)),
+            None => self.heatmap.resize(self.cells.len(), 0.0),
+        }
+        &self.heatmap
+    }
+
+    // the heatmap from the last render_heatmap, possibly stale
+    pub fn heatmap(&self) -> &[f32] {
+        &self.heatmap
+    }
+
+    // 4 bytes (R, G, B, A) per cell in row-major order: palette.dead where a cell was never
+    // alive, otherwise a palette.ages color scaled from alive once (the first) to as often
+    // as the busiest cell (the last). walls get palette.wall, without tracking it's all dead
+    pub fn export_long_exposure_rgba(&self, palette: &Palette) -> Result<Vec<u8>, Error> {
+        if palette.ages.is_empty() {
+            return Err(Error::EmptyPalette);
+        }
+
+        let empty = vec![0; self.cells.len()];
+        let exposure = self.exposure.as_deref().unwrap_or(&empty);
+        let brightest = exposure.iter().copied().max().unwrap_or(0).max(1) as u64;
+        let last = palette.ages.len() as u64 - 1;
+
+        let mut rgba = Vec::with_capacity(self.cells.len() * 4);
+        for (&cell, &count) in self.cells.iter().zip(exposure) {
+            let color = match (cell, count) {
+                (Cell::Wall, _) => palette.wall,
+                (_, 0) => palette.dead,
+                (_, count) => palette.ages[((count as u64 - 1) * last / (brightest - 1).max(1)) as usize],
+            };
+            rgba.extend_from_slice(&color.to_be_bytes());
+        }
+        Ok(rgba)
+    }
+}
@@ -0,0 +1,153 @@
+// growth-rate measurement - whether a pattern stays bounded, grows linearly (guns, puffers)
+// or quadratically (breeders), judged on a long run over an unbounded plane
+//
+// the class comes from the slope of log population against log generation over the second
+// half of the run, so the seed's own cells stop mattering once the run is long enough for the
+// growth to dominate them. the fits are least squares polynomials of the class's degree
+
+use alloc::vec::Vec;
+
+use crate::Universe;
+
+// growth exponents below these are bounded and linear, anything above is quadratic
+const LINEAR_EXPONENT: f64 = 0.5;
+const QUADRATIC_EXPONENT: f64 = 1.5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Growth {
+    Bounded,   // still lifes, oscillators, spaceships, and patterns that die out
+    Linear,    // guns and puffers
+    Quadratic, // breeders
+}
+
+impl Growth {
+    fn degree(self) -> usize {
+        match self {
+            Growth::Bounded => 0,
+            Growth::Linear => 1,
+            Growth::Quadratic => 2,
+        }
+    }
+}
+
+// result of Universe::analyze_growth
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GrowthRate {
+    pub class: Growth,
+    pub exponent: f64, // fitted power of the population's growth, ~0, ~1 or ~2
+    // polynomials in the generations since the start, [constant, per generation,
+    // per generation squared] - terms above the class's degree are 0
+    pub population: [f64; 3],
+    pub area: [f64; 3], // of the bounding box, 0 once everything is dead
+}
+
+impl Universe {
+    // run a copy of the universe for generations generations on an unbounded plane (the
+    // arena grows as the pattern spreads, up to the size measure_lifespan stops at) taking
+    // samples evenly spaced samples of the population and bounding box area, and classify
+    // how the population grows
+    pub fn analyze_growth(&self, generations: u64, samples: u32) -> GrowthRate {
+        let generations = generations.max(1);
+        let stride = (generations / samples.max(2) as u64).max(1);
+        let mut universe = self.clone();
+        universe.set_wrap(false, false);
+
+        let mut population = Vec::new();
+        let mut area = Vec::new();
+        let mut record = |universe: &Universe, generation: u64| {
+            let bounds = universe.bounding_box();
+            population.push((generation as f64, universe.population() as f64));
+            area.push((
+                generation as f64,
+                bounds.map_or(0.0, |bounds| bounds.width() as f64 * bounds.height() as f64),
+            ));
+        };
+        record(&universe, 0);
+
+        for generation in 1..=generations {
+            universe.expand_arena();
+            universe.tick(1);
+            if generation.is_multiple_of(stride) || generation == generations {
+                record(&universe, generation);
+            }
+        }
+
+        let exponent = growth_exponent(&population, generations as f64 / 2.0);
+        let class = if exponent < LINEAR_EXPONENT {
+            Growth::Bounded
+        } else if exponent < QUADRATIC_EXPONENT {
+            Growth::Linear
+        } else {
+            Growth::Quadratic
+        };
+        GrowthRate {
+            class,
+            exponent,
+            population: fit(&population, class.degree()),
+            area: fit(&area, class.degree()),
+        }
+    }
+}
+
+// slope of the least squares line through (ln t, ln y) for the samples from t = from on,
+// 0 when the population dies out in them
+fn growth_exponent(samples: &[(f64, f64)], from: f64) -> f64 {
+    let tail: Vec<(f64, f64)> = samples.iter().copied().filter(|&(t, _)| t >= from && t > 0.0).collect();
+    if tail.len() < 2 || tail.iter().any(|&(_, y)| y == 0.0) {
+        return 0.0;
+    }
+    let points: Vec<(f64, f64)> = tail.iter().map(|&(t, y)| (libm::log(t), libm::log(y))).collect();
+    fit(&points, 1)[1]
+}
+
+// least squares polynomial of degree (at most 2) through the samples
+fn fit(samples: &[(f64, f64)], degree: usize) -> [f64; 3] {
+    // t is scaled into 0..=1 first to keep the normal equations well conditioned
+    let scale = samples.iter().fold(0.0f64, |scale, &(t, _)| scale.max(libm::fabs(t)));
+    let scale = if scale > 0.0 { scale } else { 1.0 };
+    let size = degree.min(samples.len().saturating_sub(1)).min(2) + 1;
+
+    // augmented normal equations, sum of x^(i+j) | sum of y x^i
+    let mut matrix = [[0.0f64; 4]; 3];
+    for &(t, y) in samples {
+        let x = t / scale;
+        let powers = [1.0, x, x * x, x * x * x, x * x * x * x];
+        for i in 0..size {
+            for j in 0..size {
+                matrix[i][j] += powers[i + j];
+            }
+            matrix[i][3] += y * powers[i];
+        }
+    }
+
+    // gaussian elimination with partial pivoting
+    let mut coefficients = [0.0; 3];
+    for col in 0..size {
+        let pivot = (col..size)
+            .max_by(|&a, &b| libm::fabs(matrix[a][col]).total_cmp(&libm::fabs(matrix[b][col])))
+            .unwrap();
+        matrix.swap(col, pivot);
+        if matrix[col][col] == 0.0 {
+            return coefficients;
+        }
+        for row in col + 1..size {
+            let factor = matrix[row][col] / matrix[col][col];
+            let pivot_row = matrix[col];
+            for (value, pivot) in matrix[row].iter_mut().zip(pivot_row).skip(col) {
+                *value -= factor * pivot;
+            }
+        }
+    }
+    for row in (0..size).rev() {
+        let known: f64 = (row + 1..size).map(|k| matrix[row][k] * coefficients[k]).sum();
+        coefficients[row] = (matrix[row][3] - known) / matrix[row][row];
+    }
+
+    // back into terms of t
+    let mut power = 1.0;
+    for coefficient in &mut coefficients {
+        *coefficient /= power;
+        power *= scale;
+    }
+    coefficients
+}
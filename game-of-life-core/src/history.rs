@@ -0,0 +1,135 @@
+// population over the run for sparkline charts, recorded by the engine every generation so
+// nothing is lost to skipped frames
+//
+// the history holds at most capacity samples of stride generations each: once it fills up,
+// neighboring samples are merged and the stride doubles, so memory stays bounded and the
+// samples always cover the whole run
+
+use alloc::vec::Vec;
+
+use crate::{Cell, Universe};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sample {
+    pub population: f32, // average over the sample's generations
+    pub births: u32,
+    pub deaths: u32,
+}
+
+impl Sample {
+    // one sample for two consecutive ones of the same length
+    fn merge(self, next: Sample) -> Sample {
+        Sample {
+            population: (self.population + next.population) / 2.0,
+            births: self.births.saturating_add(next.births),
+            deaths: self.deaths.saturating_add(next.deaths),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PopulationHistory {
+    capacity: usize,
+    stride: u32,
+    samples: Vec<Sample>, // oldest first
+    pending: Sample,      // the sample being filled, population summed rather than averaged
+    pending_generations: u32,
+}
+
+impl PopulationHistory {
+    pub fn new(capacity: usize) -> PopulationHistory {
+        PopulationHistory {
+            // merging pairs up every sample, a lone one left over would be half the new stride
+            capacity: capacity.max(2).next_multiple_of(2),
+            stride: 1,
+            samples: Vec::new(),
+            pending: Sample::default(),
+            pending_generations: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    // generations per sample
+    pub fn stride(&self) -> u32 {
+        self.stride
+    }
+
+    pub fn samples(&self) -> &[Sample] {
+        &self.samples
+    }
+
+    // population, births, deaths of each sample in turn, e.g. for a Float32Array
+    pub fn to_f32(&self) -> Vec<f32> {
+        self.samples
+            .iter()
+            .flat_map(|sample| [sample.population, sample.births as f32, sample.deaths as f32])
+            .collect()
+    }
+
+    fn record(&mut self, population: usize, births: u32, deaths: u32) {
+        self.pending.population += population as f32;
+        self.pending.births += births;
+        self.pending.deaths += deaths;
+        self.pending_generations += 1;
+        if self.pending_generations < self.stride {
+            return;
+        }
+
+        self.samples.push(Sample {
+            population: self.pending.population / self.stride as f32,
+            ..self.pending
+        });
+        self.pending = Sample::default();
+        self.pending_generations = 0;
+
+        if self.samples.len() == self.capacity {
+            self.samples = self.samples.chunks_exact(2).map(|pair| pair[0].merge(pair[1])).collect();
+            self.stride = self.stride.saturating_mul(2);
+        }
+    }
+}
+
+impl Universe {
+    // record population, births and deaths every generation in at most capacity samples
+    // (rounded up to an even number), 0 stops recording. the history starts over whenever this is called
+    pub fn set_population_history(&mut self, capacity: usize) {
+        self.history = match capacity {
+            0 => None,
+            capacity => Some(PopulationHistory::new(capacity)),
+        };
+    }
+
+    pub fn population_history(&self) -> Option<&PopulationHistory> {
+        self.history.as_ref()
+    }
+
+    // called after every generation with the one before it
+    pub(crate) fn update_history(&mut self, previous: &[Cell]) {
+        if self.history.is_none() {
+            return;
+        }
+
+        let (population, births, deaths) = count_changes(previous, &self.cells);
+        if let Some(history) = &mut self.history {
+            history.record(population, births, deaths);
+        }
+    }
+}
+
+// population of cells, and births and deaths since previous
+pub(crate) fn count_changes(previous: &[Cell], cells: &[Cell]) -> (usize, u32, u32) {
+    let (mut population, mut births, mut deaths) = (0, 0, 0);
+    for (&old, &new) in previous.iter().zip(cells) {
+        population += (new == Cell::Alive) as usize;
+        match (old, new) {
+            (Cell::Alive, Cell::Alive) => {}
+            (_, Cell::Alive) => births += 1,
+            (Cell::Alive, _) => deaths += 1,
+            _ => {}
+        }
+    }
+    (population, births, deaths)
+}
@@ -0,0 +1,52 @@
+// periodic generation events (autosave, progress reports, ...) recorded by the engine as the
+// generations are stepped, so none are missed when a frame runs many of them or the ticks
+// happen somewhere the frontend doesn't see
+//
+// events queue up until take_hook_events, the wasm frontend hands them to a JS callback
+// after every tick call
+
+use alloc::vec::Vec;
+
+use crate::history::count_changes;
+use crate::{Cell, Universe};
+
+// the universe as of a generation a hook fired on, or a runner stopped at
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GenerationStats {
+    pub generation: u64,
+    pub population: usize,
+    pub births: u32, // since the generation before
+    pub deaths: u32,
+}
+
+impl Universe {
+    // record an event on every generation that is a multiple of interval, 0 stops them -
+    // events not taken yet are dropped either way
+    pub fn set_hook_interval(&mut self, interval: u32) {
+        self.hook_interval = interval;
+        self.hook_events.clear();
+    }
+
+    pub fn hook_interval(&self) -> u32 {
+        self.hook_interval
+    }
+
+    // the events recorded since the last call, oldest first
+    pub fn take_hook_events(&mut self) -> Vec<GenerationStats> {
+        core::mem::take(&mut self.hook_events)
+    }
+
+    // called after every generation with the one before it
+    pub(crate) fn update_hooks(&mut self, previous: &[Cell]) {
+        if self.hook_interval == 0 || !self.generation.is_multiple_of(self.hook_interval as u64) {
+            return;
+        }
+        let (population, births, deaths) = count_changes(previous, &self.cells);
+        self.hook_events.push(GenerationStats {
+            generation: self.generation,
+            population,
+            births,
+            deaths,
+        });
+    }
+}
@@ -0,0 +1,33 @@
+// seeding a universe from a picture, e.g. a logo or a drawing taken off a canvas
+
+use crate::{Cell, Error, Universe};
+
+impl Universe {
+    // one cell per pixel of width * height RGBA bytes (the layout of canvas ImageData), alive
+    // where the pixel is darker than threshold (0-255). transparent pixels are taken as drawn
+    // on white, so the empty parts of a logo stay dead
+    pub fn from_image_data(width: u32, height: u32, rgba: &[u8], threshold: u8) -> Result<Universe, Error> {
+        let expected = (width as usize) * (height as usize) * 4;
+        if rgba.len() != expected {
+            return Err(Error::ImageSize {
+                expected,
+                got: rgba.len(),
+            });
+        }
+
+        let mut universe = Universe::new(width, height);
+        for (cell, pixel) in universe.cells.iter_mut().zip(rgba.chunks_exact(4)) {
+            if luminance(pixel) < threshold as u32 {
+                *cell = Cell::Alive;
+            }
+        }
+        Ok(universe)
+    }
+}
+
+// perceived brightness (ITU-R BT.601 weights) of an RGBA pixel over a white background
+fn luminance(pixel: &[u8]) -> u32 {
+    let (r, g, b, a) = (pixel[0] as u32, pixel[1] as u32, pixel[2] as u32, pixel[3] as u32);
+    let luminance = (299 * r + 587 * g + 114 * b) / 1000;
+    (luminance * a + 255 * (255 - a)) / 255
+}
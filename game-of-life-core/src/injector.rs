@@ -0,0 +1,169 @@
+// "rain" for ambient displays: every few generations a glider or a small random soup is
+// dropped in along a random edge so the universe never settles down for good
+//
+// injections land wholly inside the grid, or across an edge that wraps, and only where the
+// pattern and the cells around it are all dead - the live structures are never touched.
+// when a few tries find no clear spot that generation's injection is skipped
+
+use alloc::vec::Vec;
+
+use crate::pattern::{Pattern, Transform};
+use crate::random::{Rng, Xorshift};
+use crate::{Cell, Universe};
+
+// spots tried per injection before giving up on it
+const ATTEMPTS: u32 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Injection {
+    Glider, // always heading into the grid
+    Soup,   // random cells in a small square
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Injector {
+    interval: u32,
+    kind: Injection,
+    soup_size: u32,
+    soup_density: f64,
+}
+
+impl Injector {
+    // inject every interval generations (at least 1), soups are 5x5 and half alive
+    pub fn new(interval: u32, kind: Injection) -> Injector {
+        Injector {
+            interval: interval.max(1),
+            kind,
+            soup_size: 5,
+            soup_density: 0.5,
+        }
+    }
+
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    pub fn kind(&self) -> Injection {
+        self.kind
+    }
+
+    pub fn set_soup(&mut self, size: u32, density: f64) {
+        self.soup_size = size.max(1);
+        self.soup_density = density.clamp(0.0, 1.0);
+    }
+
+    // a pattern and its top-left corner along a random edge, None if it can't fit
+    fn roll(&self, rng: &mut Xorshift, width: u32, height: u32, wrap_x: bool, wrap_y: bool) -> Option<(Pattern, i64, i64)> {
+        let edge = below(rng, 4);
+        let pattern = match self.kind {
+            Injection::Glider => {
+                // the glider as drawn heads down and to the right, mirror it so it heads away
+                // from its edge (top, bottom, left, right) in one of two diagonals
+                let turns = [
+                    [Transform::Identity, Transform::FlipHorizontal],
+                    [Transform::FlipVertical, Transform::Rotate180],
+                    [Transform::Identity, Transform::FlipVertical],
+                    [Transform::FlipHorizontal, Transform::Rotate180],
+                ];
+                let glider = Pattern::new(&[(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)]);
+                glider.transformed(turns[edge as usize][below(rng, 2) as usize])
+            }
+            Injection::Soup => {
+                let size = self.soup_size;
+                let mut cells = Vec::new();
+                for row in 0..size {
+                    for col in 0..size {
+                        if rng.gen_bool(self.soup_density) {
+                            cells.push((row, col));
+                        }
+                    }
+                }
+                Pattern::new(&cells)
+            }
+        };
+        if pattern.population() == 0 || pattern.width() > width || pattern.height() > height {
+            return None;
+        }
+
+        // anywhere along an edge that wraps, so far that it still fits along one that doesn't
+        let mut along = |length: u32, size: u32, wrap: bool| match wrap {
+            true => below(rng, length) as i64,
+            false => below(rng, length - size + 1) as i64,
+        };
+        let (row, col) = match edge {
+            0 => (0, along(width, pattern.width(), wrap_x)),
+            1 => ((height - pattern.height()) as i64, along(width, pattern.width(), wrap_x)),
+            2 => (along(height, pattern.height(), wrap_y), 0),
+            _ => (along(height, pattern.height(), wrap_y), (width - pattern.width()) as i64),
+        };
+        Some((pattern, row, col))
+    }
+}
+
+impl Universe {
+    // run an injector as part of every generation, None stops it
+    pub fn set_injector(&mut self, injector: Option<Injector>) {
+        self.injector = injector;
+    }
+
+    pub fn injector(&self) -> Option<&Injector> {
+        self.injector.as_ref()
+    }
+
+    pub fn injector_mut(&mut self) -> Option<&mut Injector> {
+        self.injector.as_mut()
+    }
+
+    // inject right away instead of waiting for the next interval, false when there is no
+    // injector or no clear spot was found
+    pub fn inject(&mut self) -> bool {
+        let injector = match &self.injector {
+            Some(injector) => injector.clone(),
+            None => return false,
+        };
+        (0..ATTEMPTS).any(|_| {
+            injector
+                .roll(&mut self.rng, self.width, self.height, self.wrap_x, self.wrap_y)
+                .is_some_and(|(pattern, row, col)| self.place_if_clear(&pattern, row, col))
+        })
+    }
+
+    // called after every generation
+    pub(crate) fn update_injector(&mut self) {
+        let due = self.injector.as_ref().is_some_and(|injector| self.generation.is_multiple_of(injector.interval as u64));
+        if due {
+            self.inject();
+        }
+    }
+
+    // bring pattern to life at (row, col) if its box and the ring of cells around it are dead
+    fn place_if_clear(&mut self, pattern: &Pattern, row: i64, col: i64) -> bool {
+        // across the seams the way the rules see it, twist included
+        let index = |dr: i64, dc: i64| {
+            let (row, col) = self.neighbor(row as u32, col as u32, dr, dc)?;
+            Some(self.get_index(row, col))
+        };
+        for dr in -1..=pattern.height() as i64 {
+            for dc in -1..=pattern.width() as i64 {
+                if index(dr, dc).is_some_and(|idx| self.cells[idx] != Cell::Dead) {
+                    return false;
+                }
+            }
+        }
+
+        let targets: Vec<usize> = pattern
+            .cells()
+            .iter()
+            .filter_map(|&(dr, dc)| index(dr as i64, dc as i64))
+            .collect();
+        for idx in targets {
+            self.cells[idx] = Cell::Alive;
+        }
+        true
+    }
+}
+
+// uniform in 0..n
+fn below(rng: &mut Xorshift, n: u32) -> u32 {
+    (rng.next_u64() % n.max(1) as u64) as u32
+}
@@ -0,0 +1,112 @@
+// two universes overlaid on the same grid, each running its own rule but reacting to where
+// the other one is alive - predator/prey and other ecosystem experiments
+
+use crate::{Cell, Universe};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayerId {
+    A,
+    B,
+}
+
+// what happens to a layer's cells where the other layer is alive after a generation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overlap {
+    Ignore,  // the layers don't affect each other
+    Die,     // cells covered by the other layer die, e.g. prey being eaten
+    Survive, // live cells covered by the other layer never die, e.g. predators feeding
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayeredUniverse {
+    a: Universe,
+    b: Universe,
+    overlap_a: Overlap,
+    overlap_b: Overlap,
+}
+
+impl LayeredUniverse {
+    // two empty layers running Conway's rules that ignore each other, with generators seeded
+    // apart so their soups differ
+    pub fn new(width: u32, height: u32) -> LayeredUniverse {
+        let mut b = Universe::new(width, height);
+        b.reseed(1);
+        LayeredUniverse {
+            a: Universe::new(width, height),
+            b,
+            overlap_a: Overlap::Ignore,
+            overlap_b: Overlap::Ignore,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.a.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.a.height()
+    }
+
+    // the layers are ordinary universes: set their rules, draw on them, read their cells
+    // (resizing one on its own is not supported)
+    pub fn layer(&self, id: LayerId) -> &Universe {
+        match id {
+            LayerId::A => &self.a,
+            LayerId::B => &self.b,
+        }
+    }
+
+    pub fn layer_mut(&mut self, id: LayerId) -> &mut Universe {
+        match id {
+            LayerId::A => &mut self.a,
+            LayerId::B => &mut self.b,
+        }
+    }
+
+    // how the layer reacts to the other one
+    pub fn set_overlap(&mut self, id: LayerId, overlap: Overlap) {
+        match id {
+            LayerId::A => self.overlap_a = overlap,
+            LayerId::B => self.overlap_b = overlap,
+        }
+    }
+
+    pub fn overlap(&self, id: LayerId) -> Overlap {
+        match id {
+            LayerId::A => self.overlap_a,
+            LayerId::B => self.overlap_b,
+        }
+    }
+
+    // step both layers under their own rules, then apply the overlap effects - both are
+    // worked out from the stepped layers before either is changed, so the order doesn't matter
+    pub fn tick(&mut self) {
+        let before_a = self.a.cells.clone();
+        let before_b = self.b.cells.clone();
+        self.a.tick(1);
+        self.b.tick(1);
+
+        let stepped_a = self.a.cells.clone();
+        let stepped_b = self.b.cells.clone();
+        apply_overlap(&mut self.a.cells, &before_a, &stepped_b, self.overlap_a);
+        apply_overlap(&mut self.b.cells, &before_b, &stepped_a, self.overlap_b);
+    }
+}
+
+fn apply_overlap(cells: &mut [Cell], before: &[Cell], other: &[Cell], overlap: Overlap) {
+    for ((cell, &was), &covered) in cells.iter_mut().zip(before).zip(other) {
+        // walls are left alone whatever the other layer does
+        if covered != Cell::Alive || *cell == Cell::Wall {
+            continue;
+        }
+        match overlap {
+            Overlap::Ignore => {}
+            Overlap::Die => *cell = Cell::Dead,
+            Overlap::Survive => {
+                if was == Cell::Alive {
+                    *cell = Cell::Alive;
+                }
+            }
+        }
+    }
+}
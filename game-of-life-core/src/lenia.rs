@@ -0,0 +1,98 @@
+use alloc::vec::Vec;
+
+use crate::continuous::{FloatGrid, Kernel};
+
+// Lenia: each cell convolves its neighborhood with a smooth ring-shaped kernel, maps the
+// result through a gaussian growth function and moves towards it by dt
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lenia {
+    radius: u32,
+    mu: f32,    // center of the growth function
+    sigma: f32, // width of the growth function
+    dt: f32,    // time step
+    // precomputed (delta_row, delta_col, weight) for every cell inside the kernel radius,
+    // weights are normalized to sum to 1
+    weights: Vec<(i32, i32, f32)>,
+}
+
+impl Lenia {
+    // radii below 2 leave no cells inside the kernel ring, so they are bumped up to 2
+    pub fn new(radius: u32, mu: f32, sigma: f32, dt: f32) -> Lenia {
+        let radius = radius.max(2);
+        let r = radius as i32;
+        let mut weights = Vec::new();
+
+        for delta_row in -r..=r {
+            for delta_col in -r..=r {
+                let distance = libm::sqrtf((delta_row * delta_row + delta_col * delta_col) as f32) / radius as f32;
+                let weight = kernel_shell(distance);
+                if weight > 0.0 {
+                    weights.push((delta_row, delta_col, weight));
+                }
+            }
+        }
+
+        let total: f32 = weights.iter().map(|&(_, _, weight)| weight).sum();
+        for (_, _, weight) in weights.iter_mut() {
+            *weight /= total;
+        }
+
+        Lenia {
+            radius,
+            mu,
+            sigma,
+            dt,
+            weights,
+        }
+    }
+
+    pub fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    pub fn mu(&self) -> f32 {
+        self.mu
+    }
+
+    pub fn sigma(&self) -> f32 {
+        self.sigma
+    }
+
+    pub fn dt(&self) -> f32 {
+        self.dt
+    }
+
+    // gaussian bump mapped to [-1, 1], positive near mu
+    fn growth(&self, potential: f32) -> f32 {
+        let x = (potential - self.mu) / self.sigma;
+        2.0 * libm::expf(-x * x / 2.0) - 1.0
+    }
+}
+
+impl Default for Lenia {
+    // the classic orbium-friendly parameters
+    fn default() -> Lenia {
+        Lenia::new(13, 0.15, 0.015, 0.1)
+    }
+}
+
+// smooth bump over (0, 1), zero at the center and past the radius
+fn kernel_shell(distance: f32) -> f32 {
+    if distance <= 0.0 || distance >= 1.0 {
+        return 0.0;
+    }
+    libm::expf(4.0 - 1.0 / (distance * (1.0 - distance)))
+}
+
+impl Kernel for Lenia {
+    fn next_value(&self, grid: &FloatGrid, row: u32, column: u32) -> f32 {
+        let potential: f32 = self
+            .weights
+            .iter()
+            .map(|&(delta_row, delta_col, weight)| weight * grid.get_wrapped(row, column, delta_row, delta_col))
+            .sum();
+
+        let cell = grid.get_wrapped(row, column, 0, 0);
+        (cell + self.dt * self.growth(potential)).clamp(0.0, 1.0)
+    }
+}
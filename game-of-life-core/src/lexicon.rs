@@ -0,0 +1,114 @@
+// named patterns from the Life Lexicon, bundled so they work offline
+//
+// the patterns are kept as RLE in data/lexicon.txt and compressed into the binary by build.rs.
+// the first lookup inflates them and indexes the names, only the one asked for is decoded.
+// data/census.txt rides along the same way: small still lifes and oscillators under their
+// apgcodes, for lookups by code - those aren't Lexicon names and search leaves them out
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use once_cell::race::OnceBox;
+
+use crate::pattern::Pattern;
+use crate::{Error, Universe};
+
+const LEXICON: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/lexicon.txt.deflate"));
+const CENSUS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/census.txt.deflate"));
+
+struct Entry {
+    name: &'static str,
+    rle: &'static str,
+    key: String, // the name normalized
+}
+
+static LEXICON_TEXT: OnceBox<String> = OnceBox::new();
+static LEXICON_ENTRIES: OnceBox<Vec<Entry>> = OnceBox::new();
+static CENSUS_TEXT: OnceBox<String> = OnceBox::new();
+static CENSUS_ENTRIES: OnceBox<Vec<Entry>> = OnceBox::new();
+
+// every named entry, in alphabetical order
+fn entries() -> &'static [Entry] {
+    load(&LEXICON_TEXT, &LEXICON_ENTRIES, LEXICON)
+}
+
+// every census entry, named by apgcode
+fn census() -> &'static [Entry] {
+    load(&CENSUS_TEXT, &CENSUS_ENTRIES, CENSUS)
+}
+
+// a table inflated and indexed the first time it's used
+fn load(
+    text: &'static OnceBox<String>,
+    entries: &'static OnceBox<Vec<Entry>>,
+    compressed: &[u8],
+) -> &'static [Entry] {
+    entries.get_or_init(|| {
+        let text = text.get_or_init(|| {
+            // the data is compressed by build.rs, so it always inflates
+            let bytes = miniz_oxide::inflate::decompress_to_vec(compressed).unwrap();
+            Box::new(String::from_utf8(bytes).unwrap())
+        });
+        let entries = text
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, rle)| Entry {
+                name,
+                rle,
+                key: normalize(name),
+            })
+            .collect();
+        Box::new(entries)
+    })
+}
+
+// names match ignoring case, spaces and punctuation, so "R-pentomino" finds "r-pentomino"
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+// every pattern name, in alphabetical order
+pub fn names() -> impl Iterator<Item = &'static str> {
+    entries().iter().map(|entry| entry.name)
+}
+
+// the apgcode of every census object, in alphabetical order
+pub fn census_codes() -> impl Iterator<Item = &'static str> {
+    census().iter().map(|entry| entry.name)
+}
+
+// the pattern with this name or census apgcode, if there is one
+pub fn lookup(name: &str) -> Option<Pattern> {
+    let key = normalize(name);
+    entries()
+        .iter()
+        .chain(census())
+        .find(|entry| entry.key == key)
+        // the bundled data is checked by the tests
+        .map(|entry| Pattern::from_rle(entry.rle).unwrap())
+}
+
+// names containing the query (normalized the same way), an exact match first
+pub fn search(query: &str) -> Vec<&'static str> {
+    let query = normalize(query);
+    let mut found: Vec<&Entry> = entries().iter().filter(|entry| entry.key.contains(&query)).collect();
+    if let Some(exact) = found.iter().position(|entry| entry.key == query) {
+        let entry = found.remove(exact);
+        found.insert(0, entry);
+    }
+    found.into_iter().map(|entry| entry.name).collect()
+}
+
+impl Universe {
+    // stamp a lexicon pattern with its top-left at (row, col), see stamp
+    pub fn insert_named(&mut self, name: &str, row: u32, col: u32) -> Result<(), Error> {
+        let pattern = lookup(name).ok_or_else(|| Error::UnknownPattern(String::from(name)))?;
+        self.stamp(&pattern, row, col);
+        Ok(())
+    }
+}
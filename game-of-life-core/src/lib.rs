@@ -0,0 +1,685 @@
+// platform-agnostic simulation engine - no wasm-bindgen, no web-sys, no std
+// the wasm-game-of-life crate wraps these types for JS, native frontends can use them directly
+#![no_std]
+
+extern crate alloc;
+
+pub mod error;
+pub mod bounds;
+pub mod random;
+pub mod rules;
+pub mod symmetry;
+pub mod presets;
+pub mod snapshot;
+pub mod continuous;
+pub mod lenia;
+pub mod reaction_diffusion;
+pub mod sand;
+pub mod reference;
+pub mod conformance;
+pub mod analysis;
+pub mod transform;
+pub mod lifespan;
+pub mod growth;
+pub mod viewport;
+pub mod dirty;
+pub mod text;
+pub mod age;
+pub mod sonify;
+pub mod pattern;
+pub mod apgcode;
+pub mod rle;
+pub mod region;
+pub mod image;
+pub mod exposure;
+pub mod history;
+pub mod life_history;
+pub mod hooks;
+pub mod injector;
+pub mod components;
+pub mod boolean;
+pub mod runner;
+#[cfg(feature = "lexicon")]
+pub mod lexicon;
+pub mod explorer;
+pub mod editing;
+pub mod preview;
+pub mod layers;
+pub mod walls;
+pub mod twist;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
+use random::{Rng, Xorshift};
+// use fixedbitset::FixedBitSet;
+// use std::fmt;
+
+use age::Palette;
+use bounds::Strictness;
+use dirty::Rect;
+use history::PopulationHistory;
+use hooks::GenerationStats;
+use injector::Injector;
+use pattern::Pattern;
+pub use error::Error;
+use rules::{RuleRegion, RuleTable};
+use sonify::Axis;
+use symmetry::Symmetry;
+
+#[repr(u8)] // each cell is represented by a single byte when compiled to wasm, for memory efficiency
+#[derive(Clone, Copy, Debug, PartialEq, Eq)] // derive some traits
+pub enum Cell {
+    Dead = 0, // optimization
+    Alive = 1,
+    Wall = 2, // never changes, see walls.rs
+}
+
+impl Cell {
+    // toggling a wall erases it
+    pub fn toggle(&mut self) {
+        *self = match *self {
+            Cell::Dead => Cell::Alive,
+            Cell::Alive | Cell::Wall => Cell::Dead,
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Universe {
+    width: u32,
+    height: u32,
+    cells: Vec<Cell>,
+    // cells: FixedBitSet, // of length width * height (area)
+    wrap_x: bool, // columns wrap around the left/right edges
+    wrap_y: bool, // rows wrap around the top/bottom edges
+    twist: (i32, i32), // rows / columns shifted on wraparound, see twist.rs
+    rule_table: RuleTable, // next state for every 3x3 neighborhood, see rules.rs
+    regions: Vec<RuleRegion>, // zones that override rule_table, later regions win where they overlap
+    dirty: Vec<Rect>, // what the last tick changed, see dirty.rs
+    back: Vec<Cell>, // scratch buffer the next generation is computed into
+    buffer_generation: u64, // bumped whenever cells is reallocated
+    strictness: Strictness, // how the checked editing calls treat coordinates past the edge
+    ages: Option<Vec<u16>>, // generations alive in a row per cell, when tracked - see age.rs
+    max_age: u16, // cells older than this die, 0 for no limit
+    palette: Palette,
+    rgba: Vec<u8>, // last image from render_rgba_aged
+    exposure: Option<Vec<u32>>, // generations each cell spent alive while tracked, see exposure.rs
+    exposed_generations: u32,
+    heatmap: Vec<f32>, // last image from render_heatmap
+    history: Option<PopulationHistory>, // see history.rs
+    life_history: Option<Vec<u8>>, // envelope and mark flags per cell, see life_history.rs
+    hook_interval: u32, // generations between hook events, 0 for none - see hooks.rs
+    hook_events: Vec<GenerationStats>, // events not taken yet
+    injector: Option<Injector>, // drops patterns in every few generations, see injector.rs
+    sonification: Option<(u32, Axis)>, // band count and direction, see sonify.rs
+    sound: Vec<f32>,
+    generation: u64, // generations stepped so far
+    partial_row: Option<u32>, // next row of a generation being computed in slices, see tick_partial
+    edit_symmetry: Symmetry, // mirrors every edit, see editing.rs
+    preview: Option<(Pattern, u32, u32)>, // pending placement and its top-left corner, see preview.rs
+    walls_alive: bool, // whether walls count as live neighbors, see walls.rs
+    b0_odd: bool, // B0 rules alternate between two tables, see rules::emulate_b0
+    rng: Xorshift, // every random draw the universe makes, see reseed
+}
+
+impl Universe {
+    // an empty (all dead) universe running Conway's rules on a torus
+    pub fn new(width: u32, height: u32) -> Universe {
+        // use a set of bits to represent each cell, true or false, 1 or 0
+        // let mut cells = FixedBitSet::with_capacity(size);
+
+        Universe {
+            width,
+            height,
+            cells: vec![Cell::Dead; (width * height) as usize],
+            wrap_x: true,
+            wrap_y: true,
+            twist: (0, 0),
+            rule_table: rules::conway_table(),
+            regions: Vec::new(),
+            dirty: Vec::new(),
+            back: vec![Cell::Dead; (width * height) as usize],
+            buffer_generation: 0,
+            strictness: Strictness::Strict,
+            ages: None,
+            max_age: 0,
+            palette: Palette::default(),
+            rgba: Vec::new(),
+            exposure: None,
+            exposed_generations: 0,
+            heatmap: Vec::new(),
+            history: None,
+            life_history: None,
+            hook_interval: 0,
+            hook_events: Vec::new(),
+            injector: None,
+            sonification: None,
+            sound: Vec::new(),
+            generation: 0,
+            partial_row: None,
+            edit_symmetry: Symmetry::None,
+            preview: None,
+            walls_alive: false,
+            b0_odd: false,
+            rng: Xorshift::new(0),
+        }
+    }
+
+    pub fn toggle_cell(&mut self, row: u32, col: u32) {
+        for idx in self.edit_targets(row, col) {
+            self.cells[idx].toggle();
+        }
+    }
+
+    // put a cell into a specific state, unlike toggle_cell this is safe to repeat while dragging
+    pub fn set_cell(&mut self, row: u32, col: u32, state: Cell) {
+        for idx in self.edit_targets(row, col) {
+            self.cells[idx] = state;
+        }
+    }
+
+    // set many cells in one call, coords is a flat array of row/col pairs: [r0, c0, r1, c1, ...]
+    pub fn set_cells_flat(&mut self, coords: &[u32], state: Cell) -> Result<(), Error> {
+        if !coords.len().is_multiple_of(2) {
+            return Err(Error::UnpairedCoordinates);
+        }
+
+        for pair in coords.chunks(2) {
+            self.set_cell(pair[0], pair[1], state);
+        }
+
+        Ok(())
+    }
+
+    // cells is a reference to a slice of tuples of 2 elements - row, col
+    pub fn set_cells(&mut self, cells: &[(u32, u32)]) {
+        for (row, col) in cells.iter().cloned() {
+            // set specific cells to alive (turn on)
+            // self.cells.set(idx, true);
+            self.set_cell(row, col, Cell::Alive);
+        }
+    }
+
+    // state of a single cell, None when the coordinates are outside the universe
+    pub fn get_cell(&self, row: u32, col: u32) -> Option<Cell> {
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+        Some(self.cells[self.get_index(row, col)])
+    }
+
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+
+    // whether both hold the same cells under the same rules and settings, so they will evolve
+    // identically from here - unlike ==, scratch buffers, rendered images and the generation
+    // count are ignored
+    pub fn same_state(&self, other: &Universe) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.cells == other.cells
+            && self.wrap_x == other.wrap_x
+            && self.wrap_y == other.wrap_y
+            && self.twist == other.twist
+            && self.rule_table == other.rule_table
+            && self.regions == other.regions
+            && self.ages == other.ages
+            && self.max_age == other.max_age
+            && self.walls_alive == other.walls_alive
+            // the parity flips every generation, but only B0 rules look at it
+            && (!self.has_b0() || self.b0_odd == other.b0_odd)
+    }
+
+    // whether the global rule or any region's rule has B0, see rules::emulate_b0
+    fn has_b0(&self) -> bool {
+        self.rule_table[0] != 0 || self.regions.iter().any(|region| region.table[0] != 0)
+    }
+
+    pub fn set_width(&mut self, width: u32) {
+        self.width = width;
+
+        // reset universe, no alive cells
+        // for i in 0..(width * self.height) as usize {
+        //     self.cells.set(i, false);
+        // }
+
+        // reallocated so the buffer always matches the new dimensions
+        self.replace_cells(vec![Cell::Dead; (width * self.height) as usize]);
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn set_height(&mut self, height: u32) {
+        self.height = height;
+
+        // for i in 0..(self.width * height) as usize {
+        //     self.cells.set(i, false);
+        // }
+
+        self.replace_cells(vec![Cell::Dead; (self.width * height) as usize]);
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    // enable wrapping independently per axis, e.g. (true, false) is a horizontal cylinder
+    pub fn set_wrap(&mut self, x: bool, y: bool) {
+        self.wrap_x = x;
+        self.wrap_y = y;
+    }
+
+    pub fn wrap_x(&self) -> bool {
+        self.wrap_x
+    }
+
+    pub fn wrap_y(&self) -> bool {
+        self.wrap_y
+    }
+
+    // replace the rule with an arbitrary (possibly non-totalistic) table of 512 next states,
+    // indexed by the 9-bit neighborhood described in rules.rs - any non-zero entry means alive
+    // rules with B0 are emulated so the background stays dead, the cells are taken as they are
+    // now
+    pub fn set_rule_table(&mut self, table: &[u8]) -> Result<(), Error> {
+        if table.len() != rules::TABLE_SIZE {
+            return Err(Error::RuleTableSize {
+                expected: rules::TABLE_SIZE,
+                got: table.len(),
+            });
+        }
+
+        for (entry, &next) in self.rule_table.iter_mut().zip(table) {
+            *entry = (next != 0) as u8;
+        }
+        self.b0_odd = false;
+
+        Ok(())
+    }
+
+    pub fn rule_table(&self) -> &RuleTable {
+        &self.rule_table
+    }
+
+    // run a different B/S rule (e.g. "B1357/S1357") inside a rectangle, bounds inclusive
+    // cells are always evaluated under the rule of the region they sit in, even on its border
+    pub fn add_rule_region(&mut self, row0: u32, col0: u32, row1: u32, col1: u32, rule: &str) -> Result<(), Error> {
+        let table = rules::parse_rule(rule)?;
+        self.regions.push(RuleRegion {
+            row0: row0.min(row1),
+            col0: col0.min(col1),
+            row1: row0.max(row1),
+            col1: col0.max(col1),
+            table,
+        });
+        Ok(())
+    }
+
+    pub fn clear_rule_regions(&mut self) {
+        self.regions.clear();
+    }
+
+    // translate a 2d coordinate into a 1d index
+    pub fn get_index(&self, row: u32, column: u32) -> usize {
+        (row * self.width + column) as usize
+    }
+
+    // computes total live neighbors for a given cell, honoring the wrap settings
+    pub fn live_neighbor_count(&self, row: u32, column: u32) -> u8 {
+        (self.neighborhood(row, column) & !rules::CENTER_BIT).count_ones() as u8
+    }
+
+    // live neighbor count of every cell in row-major order, same layout as the cells
+    pub fn neighbor_counts(&self) -> Vec<u8> {
+        let mut counts = Vec::with_capacity(self.cells.len());
+        for row in 0..self.height {
+            for col in 0..self.width {
+                counts.push(self.live_neighbor_count(row, col));
+            }
+        }
+        counts
+    }
+
+    // packs the 3x3 neighborhood of a cell (itself included) into a 9-bit rule table index
+    pub fn neighborhood(&self, row: u32, column: u32) -> u16 {
+        if self.is_twisted() {
+            return self.twisted_neighborhood(row, column);
+        }
+        let mut neighborhood = 0;
+        // iterate over the neighborhood in row-major order, top-left first
+        for (i, delta_row) in [self.height - 1, 0, 1].iter().cloned().enumerate() {
+            for (j, delta_col) in [self.width - 1, 0, 1].iter().cloned().enumerate() {
+                // neighbors past a non-wrapping edge are treated as dead
+                let off_top_or_bottom = delta_row != 0
+                    && ((row == 0 && delta_row == self.height - 1) || (row == self.height - 1 && delta_row == 1));
+                let off_left_or_right = delta_col != 0
+                    && ((column == 0 && delta_col == self.width - 1) || (column == self.width - 1 && delta_col == 1));
+                if (!self.wrap_y && off_top_or_bottom) || (!self.wrap_x && off_left_or_right) {
+                    continue;
+                }
+                // compute the neighbor's row and column
+                let neighbor_row = (row + delta_row) % self.height;
+                let neighbor_col = (column + delta_col) % self.width;
+                // get the index of the neighbor
+                let idx = self.get_index(neighbor_row, neighbor_col);
+                // set the neighbor's bit if it is alive
+                neighborhood |= (self.counts_as_alive(self.cells[idx]) as u16) << (i * 3 + j);
+            }
+        }
+
+        neighborhood
+    }
+
+    // update the universe state with new cells (new generation), generations times
+    pub fn tick(&mut self, generations: usize) {
+        self.advance(generations as u64);
+    }
+
+    // advance many generations in a tight loop, ping-ponging between two buffers so
+    // nothing is allocated per generation
+    // the front buffer (cells) keeps its address: after an odd number of swaps the result
+    // is copied back into it, so a view of get_cells stays valid across ticks
+    pub fn advance(&mut self, generations: u64) {
+        let mut left = generations;
+        self.advance_while(|| {
+            let more = left > 0;
+            left = left.saturating_sub(1);
+            more
+        });
+    }
+
+    // keep advancing while keep_going returns true (asked before every generation) and
+    // return how many generations ran, e.g. to fill a time budget
+    pub fn advance_while(&mut self, mut keep_going: impl FnMut() -> bool) -> u64 {
+        self.advance_checked(|_| keep_going())
+    }
+
+    // advance until done holds for the cells (checked before every generation, so a universe
+    // that already satisfies it doesn't move) and return how many generations that took, or
+    // None if it still didn't after max_generations
+    pub fn tick_until(&mut self, max_generations: u64, mut done: impl FnMut(&[Cell]) -> bool) -> Option<u64> {
+        let mut reached = false;
+        let mut left = max_generations;
+        let generations = self.advance_checked(|cells| {
+            reached = done(cells);
+            let more = !reached && left > 0;
+            left = left.saturating_sub(1);
+            more
+        });
+        reached.then_some(generations)
+    }
+
+    // e.g. how long a soup takes to die out, tick_until_population_below(1, ...)
+    pub fn tick_until_population_below(&mut self, population: usize, max_generations: u64) -> Option<u64> {
+        self.tick_until(max_generations, |cells| cells.iter().filter(|&&cell| cell == Cell::Alive).count() < population)
+    }
+
+    pub fn tick_until_population_above(&mut self, population: usize, max_generations: u64) -> Option<u64> {
+        self.tick_until(max_generations, |cells| cells.iter().filter(|&&cell| cell == Cell::Alive).count() > population)
+    }
+
+    // advance_while with the current cells handed to keep_going
+    fn advance_checked(&mut self, mut keep_going: impl FnMut(&[Cell]) -> bool) -> u64 {
+        // the back buffer gets overwritten, so any generation tick_partial was in the middle
+        // of starts over
+        self.partial_row = None;
+        let before = self.cells.clone();
+        let mut next = mem::take(&mut self.back);
+        next.resize(self.cells.len(), Cell::Dead);
+        let mut generations = 0;
+        while keep_going(&self.cells) {
+            self.step_into(&mut next);
+            mem::swap(&mut self.cells, &mut next);
+            self.b0_odd = !self.b0_odd;
+            self.generation += 1;
+            self.record_generation(&next);
+            generations += 1;
+        }
+        if generations % 2 == 1 {
+            mem::swap(&mut self.cells, &mut next);
+            self.cells.copy_from_slice(&next);
+        }
+        self.back = next;
+        self.finish_tick(&before);
+        generations
+    }
+
+    // generations stepped since the universe was created (or restored from a full snapshot)
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    // compute at most rows_budget rows of the next generation and return true once the last
+    // row is done and the generation has been applied, so a huge universe can be stepped a
+    // frame-sized slice at a time. the cells stay at the current generation until then, and
+    // editing them mid-generation mixes the edits into the rows not computed yet
+    pub fn tick_partial(&mut self, rows_budget: u32) -> bool {
+        let start = self.partial_row.unwrap_or(0);
+        let end = start.saturating_add(rows_budget).min(self.height);
+
+        let mut next = mem::take(&mut self.back);
+        next.resize(self.cells.len(), Cell::Dead);
+        self.step_rows_into(&mut next, start, end);
+
+        let done = end == self.height;
+        if done {
+            let before = self.cells.clone();
+            self.cells.copy_from_slice(&next);
+            self.b0_odd = !self.b0_odd;
+            self.generation += 1;
+            self.record_generation(&before);
+            self.finish_tick(&before);
+            self.partial_row = None;
+        } else {
+            self.partial_row = Some(end);
+        }
+        self.back = next;
+        done
+    }
+
+    // whether tick_partial is in the middle of a generation
+    pub fn tick_in_progress(&self) -> bool {
+        self.partial_row.is_some()
+    }
+
+    // per-generation bookkeeping, run right after each generation is stepped
+    fn record_generation(&mut self, previous: &[Cell]) {
+        self.update_ages();
+        self.update_exposure();
+        self.update_history(previous);
+        self.update_life_history();
+        self.update_hooks(previous);
+        self.update_injector();
+    }
+
+    // per-tick summaries of what changed since before
+    fn finish_tick(&mut self, before: &[Cell]) {
+        self.dirty = dirty::coalesce(before, &self.cells, self.width, self.height);
+        self.update_sound(before);
+    }
+
+    // changes whenever the cell buffer is reallocated (resizes, imports), which invalidates
+    // any pointer to or view of get_cells taken before
+    pub fn buffer_generation(&self) -> u64 {
+        self.buffer_generation
+    }
+
+    // swap in a freshly allocated cell buffer, e.g. after the dimensions change
+    pub(crate) fn replace_cells(&mut self, cells: Vec<Cell>) {
+        self.back = vec![Cell::Dead; cells.len()];
+        self.cells = cells;
+        self.buffer_generation += 1;
+        // the old rectangles may not even fit the new dimensions, and a half computed
+        // generation is meaningless now
+        self.dirty.clear();
+        self.partial_row = None;
+        if self.ages.is_some() {
+            self.set_track_ages(true);
+        }
+        if self.exposure.is_some() {
+            self.set_track_exposure(true);
+        }
+        if self.life_history.is_some() {
+            self.set_life_history(true);
+        }
+        if let Some(capacity) = self.history.as_ref().map(|history| history.capacity()) {
+            self.set_population_history(capacity);
+        }
+    }
+
+    // rule table that governs a cell - the last region containing it, or the global rule
+    fn rule_table_at(&self, row: u32, col: u32) -> &RuleTable {
+        self.region_at(row, col).map_or(&self.rule_table, |i| &self.regions[i].table)
+    }
+
+    // index of the region whose rule applies at (row, col), if any
+    fn region_at(&self, row: u32, col: u32) -> Option<usize> {
+        self.regions.iter().rposition(|region| region.contains(row, col))
+    }
+
+    // coordinate delta steps away along an axis, None past a non-wrapping edge
+    fn step_coord(coord: u32, delta: i64, size: u32, wrap: bool) -> Option<u32> {
+        let moved = coord as i64 + delta;
+        if moved < 0 || moved >= size as i64 {
+            if !wrap {
+                return None;
+            }
+            return Some(moved.rem_euclid(size as i64) as u32);
+        }
+        Some(moved as u32)
+    }
+
+    // the three cells of a neighborhood column packed as bits 0, 3 and 6 (top to bottom)
+    fn column_bits(&self, rows: &[Option<u32>; 3], col: Option<u32>) -> u16 {
+        let col = match col {
+            Some(col) => col,
+            None => return 0,
+        };
+        let mut bits = 0;
+        for (i, row) in rows.iter().enumerate() {
+            if let Some(row) = *row {
+                bits |= (self.counts_as_alive(self.cells[self.get_index(row, col)]) as u16) << (i * 3);
+            }
+        }
+        bits
+    }
+
+    // compute a single generation into next with one rule table lookup per cell
+    // the neighborhood slides along each row a column at a time instead of being re-read
+    fn step_into(&self, next: &mut [Cell]) {
+        self.step_rows_into(next, 0, self.height);
+    }
+
+    // step_into for rows start..end only
+    fn step_rows_into(&self, next: &mut [Cell], start: u32, end: u32) {
+        let main = rules::emulate_b0(&self.rule_table, self.b0_odd);
+        let regions: Vec<RuleTable> = self
+            .regions
+            .iter()
+            .map(|region| rules::emulate_b0(&region.table, self.b0_odd))
+            .collect();
+        let twisted = self.is_twisted();
+
+        for row in start..end {
+            let rows = [
+                Universe::step_coord(row, -1, self.height, self.wrap_y),
+                Some(row),
+                Universe::step_coord(row, 1, self.height, self.wrap_y),
+            ];
+
+            let mut left = self.column_bits(&rows, Universe::step_coord(0, -1, self.width, self.wrap_x));
+            let mut center = self.column_bits(&rows, Some(0));
+
+            for col in 0..self.width {
+                let right = self.column_bits(&rows, Universe::step_coord(col, 1, self.width, self.wrap_x));
+                // a twist breaks the columns up along the edges, see twist.rs
+                let neighborhood = match twisted {
+                    true => self.twisted_neighborhood(row, col),
+                    false => left | (center << 1) | (right << 2),
+                };
+
+                let table = if regions.is_empty() {
+                    &main
+                } else {
+                    self.region_at(row, col).map_or(&main, |i| &regions[i])
+                };
+                let idx = self.get_index(row, col);
+                next[idx] = match table[neighborhood as usize] {
+                    _ if self.cells[idx] == Cell::Wall => Cell::Wall,
+                    0 => Cell::Dead,
+                    _ => Cell::Alive,
+                };
+
+                left = center;
+                center = right;
+            }
+        }
+    }
+
+    // fill up universe with cells, both alive and dead (50% chance each), walls are kept
+    pub fn randomize(&mut self) {
+        let rng = &mut self.rng;
+        for cell in self.cells.iter_mut().filter(|cell| **cell != Cell::Wall) {
+            *cell = if rng.gen_bool(0.5) { Cell::Alive } else { Cell::Dead };
+        }
+    }
+
+    // random soup where each cell is alive with probability density, kept perfectly symmetric
+    pub fn randomize_symmetric(&mut self, density: f64, symmetry: Symmetry) {
+        let mut rng = self.rng.clone();
+        let density = density.clamp(0.0, 1.0);
+        let mut decided = vec![false; self.cells.len()];
+
+        for row in 0..self.height {
+            for col in 0..self.width {
+                if decided[self.get_index(row, col)] {
+                    continue;
+                }
+
+                // roll once per orbit and copy the result to every mirrored cell
+                let state = if rng.gen_bool(density) { Cell::Alive } else { Cell::Dead };
+                for (image_row, image_col) in symmetry.images(row, col, self.width, self.height) {
+                    let idx = self.get_index(image_row, image_col);
+                    if self.cells[idx] != Cell::Wall {
+                        self.cells[idx] = state;
+                    }
+                    decided[idx] = true;
+                }
+            }
+        }
+        self.rng = rng;
+    }
+
+    // kill every cell, walls stay - see clear_walls
+    pub fn die(&mut self) {
+        for cell in self.cells.iter_mut().filter(|cell| **cell == Cell::Alive) {
+            *cell = Cell::Dead;
+        }
+    }
+}
+
+// implement Display trait to convert to String for printing
+// impl fmt::Display for Universe {
+//     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//         // get the slice of the vector that contains the data, don't want entire growable vector
+//         // divide the vector of cells into lines of length the width of the universe (creating row)
+//         for line in self.cells.as_slice().chunks(self.width as usize) {
+//             for &cell in line {
+//                 // let symbol = if cell == Cell::Dead {
+//                 let symbol = if cell == 0 {
+//                     '◻'
+//                 } else {
+//                     '◼'
+//                 };
+//                 write!(f, "{}", symbol)?;
+//             }
+//             write!(f, "\n")?;
+//         }
+
+//         Ok(())
+//     }
+// }
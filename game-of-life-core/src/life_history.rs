@@ -0,0 +1,157 @@
+// a LifeHistory mode as in Golly: next to the live cells, every cell remembers whether it was
+// ever alive (the envelope) and can be marked by hand, so construction diagrams show where
+// the reactions went and which cells matter
+//
+// the cells still run the universe's rule, the history only rides along and has no effect
+// on the evolution. patterns are exchanged as LifeHistory RLE, with Golly's states:
+//
+//     0 '.' dead            1 'A' alive            2 'B' dead, in the envelope
+//     3 'C' alive, marked   4 'D' dead, marked     5 'E' alive since the start
+//     6 'F' boundary, read and written as a wall
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::age::Palette;
+use crate::rle::{read_rle, rle_size, write_rle, MAX_CELLS};
+use crate::{Cell, Error, Universe};
+
+// flags per cell
+const ENVELOPE: u8 = 1; // alive at some point while the mode was on
+const MARKED: u8 = 2;
+const START: u8 = 4; // alive without a break since it was imported as state 5
+
+impl Universe {
+    // turn the mode on with the live cells as the whole envelope so far, or off dropping the
+    // envelope and marks
+    pub fn set_life_history(&mut self, enabled: bool) {
+        self.life_history = match enabled {
+            true => Some(self.cells.iter().map(|&cell| (cell == Cell::Alive) as u8 * ENVELOPE).collect()),
+            false => None,
+        };
+    }
+
+    pub fn has_life_history(&self) -> bool {
+        self.life_history.is_some()
+    }
+
+    // mark or unmark a cell, turning the mode on if it's off
+    pub fn set_marked(&mut self, row: u32, col: u32, marked: bool) -> Result<(), Error> {
+        let idx = self.checked_index(row, col)?;
+        if self.life_history.is_none() {
+            self.set_life_history(true);
+        }
+        let flags = &mut self.life_history.as_mut().unwrap()[idx];
+        *flags = if marked { *flags | MARKED } else { *flags & !MARKED };
+        Ok(())
+    }
+
+    // forget where the cells have been, keeping the marks - the live cells start a new envelope
+    pub fn clear_envelope(&mut self) {
+        if let Some(history) = &mut self.life_history {
+            for (flags, &cell) in history.iter_mut().zip(&self.cells) {
+                *flags = (*flags & MARKED) | ((cell == Cell::Alive) as u8 * ENVELOPE);
+            }
+        }
+    }
+
+    // the LifeHistory state (0-6, see above) of every cell in row-major order, None when the
+    // mode is off
+    pub fn life_history_states(&self) -> Option<Vec<u8>> {
+        let history = self.life_history.as_ref()?;
+        Some(self.cells.iter().zip(history).map(|(&cell, &flags)| state(cell, flags)).collect())
+    }
+
+    // the whole grid as LifeHistory RLE, plain live and dead cells when the mode is off
+    pub fn to_life_history_rle(&self) -> String {
+        let no_history = vec![0; self.cells.len()];
+        let history = self.life_history.as_deref().unwrap_or(&no_history);
+        write_rle(self.width, self.height, Some("LifeHistory"), '.', |row, col| {
+            let idx = self.get_index(row, col);
+            letter(state(self.cells[idx], history[idx]))
+        })
+    }
+
+    // a universe in LifeHistory mode from LifeHistory RLE, as large as its header says or as
+    // the pattern when there is none. 'b' and 'o' are read as states 0 and 1, so plain Life
+    // RLE works too
+    pub fn from_life_history_rle(text: &str) -> Result<Universe, Error> {
+        let mut states = Vec::new();
+        read_rle(text, |row, col, c| {
+            let state = match c {
+                'o' => 1,
+                'A'..='F' => c as u8 - b'A' + 1,
+                c => return Err(Error::UnexpectedChar { c, row, col }),
+            };
+            states.push((row, col, state));
+            Ok(())
+        })?;
+
+        let (width, height) = rle_size(text).unwrap_or_else(|| {
+            let width = states.iter().map(|&(_, col, _)| col + 1).max().unwrap_or(0);
+            let height = states.iter().map(|&(row, _, _)| row + 1).max().unwrap_or(0);
+            (width, height)
+        });
+        if width.checked_mul(height).is_none_or(|cells| cells > MAX_CELLS) {
+            return Err(Error::TooLarge { width, height });
+        }
+        let mut universe = Universe::new(width, height);
+        universe.set_life_history(true);
+        for (row, col, state) in states {
+            let idx = universe.checked_index(row, col)?;
+            let (cell, flags) = match state {
+                1 => (Cell::Alive, ENVELOPE),
+                2 => (Cell::Dead, ENVELOPE),
+                3 => (Cell::Alive, ENVELOPE | MARKED),
+                4 => (Cell::Dead, MARKED),
+                5 => (Cell::Alive, ENVELOPE | START),
+                _ => (Cell::Wall, 0),
+            };
+            universe.cells[idx] = cell;
+            universe.life_history.as_mut().unwrap()[idx] = flags;
+        }
+        Ok(universe)
+    }
+
+    // called after every generation
+    pub(crate) fn update_life_history(&mut self) {
+        if let Some(history) = &mut self.life_history {
+            for (flags, &cell) in history.iter_mut().zip(&self.cells) {
+                *flags = match cell {
+                    Cell::Alive => *flags | ENVELOPE,
+                    _ => *flags & !START,
+                };
+            }
+        }
+    }
+}
+
+// color for a cell its history sets apart, None for the usual colors
+pub(crate) fn history_color(palette: &Palette, cell: Cell, flags: u8) -> Option<u32> {
+    match cell {
+        Cell::Alive if flags & MARKED != 0 => Some(palette.marked_alive),
+        Cell::Dead if flags & MARKED != 0 => Some(palette.marked_dead),
+        Cell::Dead if flags & ENVELOPE != 0 => Some(palette.envelope),
+        _ => None,
+    }
+}
+
+fn state(cell: Cell, flags: u8) -> u8 {
+    match cell {
+        Cell::Wall => 6,
+        Cell::Alive if flags & MARKED != 0 => 3,
+        Cell::Alive if flags & START != 0 => 5,
+        Cell::Alive => 1,
+        Cell::Dead if flags & MARKED != 0 => 4,
+        Cell::Dead if flags & ENVELOPE != 0 => 2,
+        Cell::Dead => 0,
+    }
+}
+
+fn letter(state: u8) -> char {
+    match state {
+        0 => '.',
+        state => (b'A' + state - 1) as char,
+    }
+}
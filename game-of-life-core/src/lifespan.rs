@@ -0,0 +1,108 @@
+// methuselah measurement - how long a pattern takes to settle down
+
+use alloc::vec::Vec;
+
+use crate::{Cell, Universe};
+
+// longest oscillator period recognized as stable
+const MAX_PERIOD: usize = 30;
+// the population must repeat for this many generations before the pattern counts as stable
+const STABLE_WINDOW: usize = 100;
+// grow the arena once live cells get this close to an edge
+const EDGE: u32 = 2;
+// cells added to a side each time the arena grows
+const EXPAND_MARGIN: u32 = 32;
+// the arena stops growing at this size, past it escaping objects are deleted at the edge
+const MAX_SIDE: u32 = 4096;
+
+// result of Universe::measure_lifespan
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lifespan {
+    pub generations: u64, // generation at which the final, periodic state was reached
+    pub period: u32,      // period of the population count once stable, 1 for still lifes
+    // final population. escaped gliders count while the arena can still grow, but once a side
+    // reaches MAX_SIDE whatever reaches the edge is deleted - they drop out of the count, and
+    // generations is then when the population settled after the last of them was deleted
+    pub population: usize,
+}
+
+impl Universe {
+    // run a copy of the universe from the current state on an unbounded plane (no wrapping,
+    // the arena grows as the pattern spreads) until it stabilizes into still lifes, oscillators
+    // and escaping gliders, or max_gens generations pass - None when it hasn't settled by then.
+    // stability is judged on the population count repeating with a period up to MAX_PERIOD
+    // for STABLE_WINDOW generations, so max_gens has to leave room for that window. the arena
+    // stops growing at MAX_SIDE cells a side, then escaping objects are deleted at its edge
+    // and no longer counted, see Lifespan
+    pub fn measure_lifespan(&self, max_gens: u64) -> Option<Lifespan> {
+        let mut universe = self.clone();
+        universe.set_wrap(false, false);
+
+        let mut history = Vec::new();
+        history.push(universe.population());
+
+        for _ in 0..max_gens {
+            universe.expand_arena();
+            universe.tick(1);
+            history.push(universe.population());
+
+            if let Some((generations, period)) = stable_since(&history) {
+                return Some(Lifespan {
+                    generations: generations as u64,
+                    period: period as u32,
+                    population: *history.last().unwrap(),
+                });
+            }
+        }
+
+        None
+    }
+
+    // grow the grid on every side the pattern is about to reach, or delete whatever reaches
+    // the edge once the arena is as large as it gets
+    pub(crate) fn expand_arena(&mut self) {
+        let bounds = match self.bounding_box() {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        let grow = |near_edge: bool, side: u32| if near_edge && side < MAX_SIDE { EXPAND_MARGIN } else { 0 };
+        let top = grow(bounds.min_row < EDGE, self.height);
+        let bottom = grow(bounds.max_row + EDGE >= self.height, self.height);
+        let left = grow(bounds.min_col < EDGE, self.width);
+        let right = grow(bounds.max_col + EDGE >= self.width, self.width);
+
+        if top + bottom + left + right > 0 {
+            let width = (self.width + left + right).min(MAX_SIDE);
+            let height = (self.height + top + bottom).min(MAX_SIDE);
+            self.reframe(width, height, -(top as i64), -(left as i64));
+        }
+
+        if self.width >= MAX_SIDE || self.height >= MAX_SIDE {
+            for row in 0..self.height {
+                for col in 0..self.width {
+                    if row < EDGE || col < EDGE || row + EDGE >= self.height || col + EDGE >= self.width {
+                        let idx = self.get_index(row, col);
+                        self.cells[idx] = Cell::Dead;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// if the tail of the population history has been periodic for STABLE_WINDOW generations,
+// the generation that periodic run started at and its period
+fn stable_since(history: &[usize]) -> Option<(usize, usize)> {
+    let last = history.len() - 1;
+    let period = (1..=MAX_PERIOD).find(|&period| {
+        last >= STABLE_WINDOW + period && (last - STABLE_WINDOW..=last).all(|gen| history[gen] == history[gen - period])
+    })?;
+
+    // walk back to the first generation of the periodic run
+    let mut start = last - STABLE_WINDOW;
+    while start >= 1 && history[start - 1] == history[start - 1 + period] {
+        start -= 1;
+    }
+    Some((start, period))
+}
@@ -0,0 +1,215 @@
+// patterns detached from the grid - a set of live cells normalized to their bounding box -
+// and canonical forms for recognizing the same object in any position and orientation
+
+use alloc::vec::Vec;
+
+use crate::{Cell, Error, Universe};
+
+// FNV-1a, small and stable across platforms and versions, hashes must be comparable between runs
+const FNV_OFFSET: u64 = 0xCBF29CE484222325;
+const FNV_PRIME: u64 = 0x100000001B3;
+
+// the rotations and reflections a pattern can be placed in, rotations are clockwise
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transform {
+    Identity = 0,
+    Rotate90 = 1,
+    Rotate180 = 2,
+    Rotate270 = 3,
+    FlipHorizontal = 4, // mirrored left to right
+    FlipVertical = 5,   // mirrored top to bottom
+    Transpose = 6,      // mirrored across the main diagonal
+    AntiTranspose = 7,  // mirrored across the other diagonal
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pattern {
+    height: u32,
+    width: u32,
+    cells: Vec<(u32, u32)>, // live (row, col) offsets from the top-left of the bounding box, sorted
+}
+
+impl Pattern {
+    // the pattern formed by some live cells, wherever they are
+    pub fn new(cells: &[(u32, u32)]) -> Pattern {
+        Pattern::normalized(cells.iter().map(|&(row, col)| (row as i64, col as i64)).collect())
+    }
+
+    // shift signed coordinates so the bounding box starts at (0, 0)
+    fn normalized(mut cells: Vec<(i64, i64)>) -> Pattern {
+        let min_row = cells.iter().map(|&(row, _)| row).min().unwrap_or(0);
+        let min_col = cells.iter().map(|&(_, col)| col).min().unwrap_or(0);
+        let mut cells: Vec<(u32, u32)> = cells
+            .drain(..)
+            .map(|(row, col)| ((row - min_row) as u32, (col - min_col) as u32))
+            .collect();
+        cells.sort_unstable();
+        cells.dedup();
+
+        let height = cells.iter().map(|&(row, _)| row + 1).max().unwrap_or(0);
+        let width = cells.iter().map(|&(_, col)| col + 1).max().unwrap_or(0);
+        Pattern { height, width, cells }
+    }
+
+    // parse ASCII art ('.' dead, 'O' alive) as Universe::from_string does
+    pub fn from_string(text: &str) -> Result<Pattern, Error> {
+        Ok(Pattern::from_universe(&Universe::from_string(text)?))
+    }
+
+    // every live cell of the universe as one pattern
+    pub fn from_universe(universe: &Universe) -> Pattern {
+        let width = universe.width();
+        let live: Vec<(u32, u32)> = universe
+            .get_cells()
+            .iter()
+            .enumerate()
+            .filter(|&(_, &cell)| cell == Cell::Alive)
+            .map(|(idx, _)| (idx as u32 / width, idx as u32 % width))
+            .collect();
+        Pattern::new(&live)
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn cells(&self) -> &[(u32, u32)] {
+        &self.cells
+    }
+
+    pub fn population(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn contains(&self, row: u32, col: u32) -> bool {
+        self.cells.binary_search(&(row, col)).is_ok()
+    }
+
+    // the distinct rotations and reflections of the pattern, itself first
+    pub fn orientations(&self) -> Vec<Pattern> {
+        let mut orientations: Vec<Pattern> = Vec::with_capacity(8);
+        for transform in 0..8 {
+            let pattern = Pattern::normalized(
+                self.cells
+                    .iter()
+                    .map(|&(row, col)| {
+                        let (row, col) = (row as i64, col as i64);
+                        // bit 2 transposes, bits 0 and 1 mirror the rows and columns
+                        let (row, col) = if transform & 4 != 0 { (col, row) } else { (row, col) };
+                        let row = if transform & 1 != 0 { -row } else { row };
+                        let col = if transform & 2 != 0 { -col } else { col };
+                        (row, col)
+                    })
+                    .collect(),
+            );
+            if !orientations.contains(&pattern) {
+                orientations.push(pattern);
+            }
+        }
+        orientations
+    }
+
+    // the pattern rotated or reflected, normalized back to its bounding box
+    pub fn transformed(&self, transform: Transform) -> Pattern {
+        Pattern::normalized(
+            self.cells
+                .iter()
+                .map(|&(row, col)| {
+                    let (row, col) = (row as i64, col as i64);
+                    match transform {
+                        Transform::Identity => (row, col),
+                        Transform::Rotate90 => (col, -row),
+                        Transform::Rotate180 => (-row, -col),
+                        Transform::Rotate270 => (-col, row),
+                        Transform::FlipHorizontal => (row, -col),
+                        Transform::FlipVertical => (-row, col),
+                        Transform::Transpose => (col, row),
+                        Transform::AntiTranspose => (-col, -row),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    // the same representative for a pattern in any position, rotation or reflection
+    pub fn canonical(&self) -> Pattern {
+        self.orientations().into_iter().min().unwrap()
+    }
+
+    // hash of the canonical form, equal for any two copies of the same object
+    pub fn canonical_hash(&self) -> u64 {
+        self.canonical().stable_hash()
+    }
+
+    // hash of this exact orientation
+    pub fn stable_hash(&self) -> u64 {
+        let mut hash = FNV_OFFSET;
+        let mut feed = |value: u32| {
+            for byte in value.to_le_bytes() {
+                hash = (hash ^ byte as u64).wrapping_mul(FNV_PRIME);
+            }
+        };
+        feed(self.height);
+        feed(self.width);
+        for &(row, col) in &self.cells {
+            feed(row);
+            feed(col);
+        }
+        hash
+    }
+}
+
+impl Universe {
+    // canonical hash of the whole live population, see Pattern::canonical_hash
+    pub fn canonical_hash(&self) -> u64 {
+        Pattern::from_universe(self).canonical_hash()
+    }
+
+    // top-left corners of every place the pattern's bounding box matches exactly (its live
+    // cells alive, the rest of the box not), in row-major order - with any_orientation its
+    // rotations and reflections count too. boxes can wrap around the edges that wrap
+    pub fn find_pattern(&self, pattern: &Pattern, any_orientation: bool) -> Vec<(u32, u32)> {
+        if pattern.cells.is_empty() {
+            return Vec::new();
+        }
+        let orientations = match any_orientation {
+            true => pattern.orientations(),
+            false => alloc::vec![pattern.clone()],
+        };
+
+        let mut found = Vec::new();
+        for row in 0..self.height {
+            for col in 0..self.width {
+                if orientations.iter().any(|orientation| self.matches_at(orientation, row, col)) {
+                    found.push((row, col));
+                }
+            }
+        }
+        found
+    }
+
+    fn matches_at(&self, pattern: &Pattern, row: u32, col: u32) -> bool {
+        // boxes past a hard edge, or big enough to wrap onto themselves, never match
+        let fits = |start: u32, size: u32, length: u32, wrap: bool| match wrap {
+            true => size <= length,
+            false => start + size <= length,
+        };
+        if !fits(row, pattern.height, self.height, self.wrap_y) || !fits(col, pattern.width, self.width, self.wrap_x) {
+            return false;
+        }
+
+        for dr in 0..pattern.height {
+            for dc in 0..pattern.width {
+                let idx = self.get_index((row + dr) % self.height, (col + dc) % self.width);
+                if (self.cells[idx] == Cell::Alive) != pattern.contains(dr, dc) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
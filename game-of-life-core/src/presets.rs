@@ -0,0 +1,56 @@
+use crate::random::Rng;
+
+use crate::{Cell, Universe};
+
+// procedurally generated starting conditions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillPreset {
+    Checkerboard = 0,
+    HorizontalStripes = 1,
+    VerticalStripes = 2,
+    DiagonalStripes = 3,
+    ConcentricRings = 4,
+    CentralBlob = 5, // dense random disc in the middle, dead everywhere else
+    SparseNoise = 6, // 10% alive
+    DenseNoise = 7,  // 50% alive
+}
+
+// width of a stripe or ring, in cells
+const BAND: u32 = 2;
+
+impl Universe {
+    // replace every cell but the walls with the given preset, the universe's generator drives
+    // the noisy ones
+    pub fn fill(&mut self, preset: FillPreset) {
+        let mut rng = self.rng.clone();
+        let center_row = self.height as f64 / 2.0;
+        let center_col = self.width as f64 / 2.0;
+        let blob_radius = self.width.min(self.height) as f64 / 4.0;
+
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let idx = self.get_index(row, col);
+                if self.cells[idx] == Cell::Wall {
+                    continue;
+                }
+                let delta_row = row as f64 + 0.5 - center_row;
+                let delta_col = col as f64 + 0.5 - center_col;
+                let distance = libm::sqrt(delta_row * delta_row + delta_col * delta_col);
+
+                let alive = match preset {
+                    FillPreset::Checkerboard => (row + col).is_multiple_of(2),
+                    FillPreset::HorizontalStripes => (row / BAND).is_multiple_of(2),
+                    FillPreset::VerticalStripes => (col / BAND).is_multiple_of(2),
+                    FillPreset::DiagonalStripes => ((row + col) / BAND).is_multiple_of(2),
+                    FillPreset::ConcentricRings => (distance as u32 / BAND).is_multiple_of(2),
+                    FillPreset::CentralBlob => distance < blob_radius && rng.gen_bool(0.5),
+                    FillPreset::SparseNoise => rng.gen_bool(0.1),
+                    FillPreset::DenseNoise => rng.gen_bool(0.5),
+                };
+
+                self.cells[idx] = if alive { Cell::Alive } else { Cell::Dead };
+            }
+        }
+        self.rng = rng;
+    }
+}
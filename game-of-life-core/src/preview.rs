@@ -0,0 +1,67 @@
+// a pending pattern placement shown as a ghost over the grid - the cells stay untouched
+// until commit_preview stamps it
+//
+// the ghost covers exactly the cells stamp would bring to life: wrapped or clipped at the
+// edges and mirrored by the edit symmetry
+
+use alloc::vec::Vec;
+
+use crate::pattern::{Pattern, Transform};
+use crate::Universe;
+
+impl Universe {
+    // show pattern, rotated or reflected, with its top-left corner at (row, col), replacing
+    // any earlier preview - call it again as the pointer moves
+    pub fn set_preview(&mut self, pattern: &Pattern, row: u32, col: u32, transform: Transform) {
+        self.preview = Some((pattern.transformed(transform), row, col));
+    }
+
+    pub fn clear_preview(&mut self) {
+        self.preview = None;
+    }
+
+    pub fn has_preview(&self) -> bool {
+        self.preview.is_some()
+    }
+
+    // indices of the cells the preview would bring to life, empty without one
+    pub fn preview_cells(&self) -> Vec<usize> {
+        let (pattern, row, col) = match &self.preview {
+            Some(preview) => preview,
+            None => return Vec::new(),
+        };
+        let mut targets: Vec<usize> = pattern
+            .cells()
+            .iter()
+            .filter_map(|&(dr, dc)| self.offset(*row, *col, dr as i64, dc as i64))
+            .flat_map(|(row, col)| self.edit_targets(row, col))
+            .collect();
+        targets.sort_unstable();
+        targets.dedup();
+        targets
+    }
+
+    // stamp the preview onto the grid and clear it, false if there was none
+    pub fn commit_preview(&mut self) -> bool {
+        match self.preview.take() {
+            Some((pattern, row, col)) => {
+                self.stamp(&pattern, row, col);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // blend the palette's preview color over its cells in the rgba buffer, by the color's alpha
+    pub(crate) fn draw_preview(&mut self) {
+        let [red, green, blue, alpha] = self.palette.preview.to_be_bytes();
+        let alpha = alpha as u32;
+        for idx in self.preview_cells() {
+            let pixel = &mut self.rgba[idx * 4..][..4];
+            for (channel, color) in pixel.iter_mut().zip([red, green, blue]) {
+                *channel = ((color as u32 * alpha + *channel as u32 * (255 - alpha)) / 255) as u8;
+            }
+            pixel[3] = (alpha + pixel[3] as u32 * (255 - alpha) / 255) as u8;
+        }
+    }
+}
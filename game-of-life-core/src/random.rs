@@ -0,0 +1,58 @@
+// tiny seedable random number generator, so neither the engine nor the wasm build needs
+// the rand crate
+//
+// each universe owns one generator that randomize, the noisy fill presets and the injector
+// all draw from, so reseeding it replays a run exactly
+
+use crate::Universe;
+
+// source of random bits for everything in the engine that rolls dice
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+
+    // true with probability p (clamped to 0-1)
+    fn gen_bool(&mut self, p: f64) -> bool {
+        // 53 random bits, uniform in [0, 1)
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    // uniform in [0, 1)
+    fn gen_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+// xorshift64* - a few instructions per number and good enough statistics for soups
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xorshift {
+    state: u64,
+}
+
+impl Xorshift {
+    // any seed works, including 0
+    pub fn new(seed: u64) -> Xorshift {
+        // run the seed through splitmix64 so similar seeds give unrelated streams, and
+        // the state is never the all-zero value xorshift gets stuck on
+        let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+        Xorshift { state: z.max(1) }
+    }
+}
+
+impl Rng for Xorshift {
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+}
+
+impl Universe {
+    // restart the universe's generator, a new universe starts from seed 0
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = Xorshift::new(seed);
+    }
+}
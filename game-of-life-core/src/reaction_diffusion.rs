@@ -0,0 +1,127 @@
+use crate::continuous::FloatGrid;
+
+// Gray-Scott reaction-diffusion: two chemicals u and v diffuse across the grid while
+// u is fed in, v is killed off, and u + 2v -> 3v converts u into v
+pub struct ReactionDiffusion {
+    u: FloatGrid,
+    v: FloatGrid,
+    feed: f32,
+    kill: f32,
+    diffusion_u: f32,
+    diffusion_v: f32,
+}
+
+impl ReactionDiffusion {
+    pub fn new(width: u32, height: u32) -> ReactionDiffusion {
+        let mut u = FloatGrid::new(width, height);
+        u.values_mut().fill(1.0);
+
+        ReactionDiffusion {
+            u,
+            v: FloatGrid::new(width, height),
+            // the "mitosis" preset, produces self-replicating spots
+            feed: 0.0367,
+            kill: 0.0649,
+            diffusion_u: 1.0,
+            diffusion_v: 0.5,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.u.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.u.height()
+    }
+
+    pub fn feed(&self) -> f32 {
+        self.feed
+    }
+
+    pub fn set_feed(&mut self, feed: f32) {
+        self.feed = feed;
+    }
+
+    pub fn kill(&self) -> f32 {
+        self.kill
+    }
+
+    pub fn set_kill(&mut self, kill: f32) {
+        self.kill = kill;
+    }
+
+    pub fn set_diffusion(&mut self, diffusion_u: f32, diffusion_v: f32) {
+        self.diffusion_u = diffusion_u;
+        self.diffusion_v = diffusion_v;
+    }
+
+
+    // drop a square of chemical v centered on (row, col)
+    pub fn seed(&mut self, row: u32, col: u32, radius: u32) {
+        let r = radius as i32;
+        for delta_row in -r..=r {
+            for delta_col in -r..=r {
+                let seed_row = (row as i64 + delta_row as i64).rem_euclid(self.height() as i64) as u32;
+                let seed_col = (col as i64 + delta_col as i64).rem_euclid(self.width() as i64) as u32;
+                let idx = self.u.get_index(seed_row, seed_col);
+                self.u.values_mut()[idx] = 0.5;
+                self.v.values_mut()[idx] = 0.25;
+            }
+        }
+    }
+
+    // reset both chemicals to the initial state (all u, no v)
+    pub fn clear(&mut self) {
+        self.u.values_mut().fill(1.0);
+        self.v.values_mut().fill(0.0);
+    }
+
+    // advance the simulation by one time step
+    pub fn tick(&mut self) {
+        let mut next_u = FloatGrid::new(self.width(), self.height());
+        let mut next_v = FloatGrid::new(self.width(), self.height());
+
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                let idx = self.u.get_index(row, col);
+                let u = self.u.values()[idx];
+                let v = self.v.values()[idx];
+                let reaction = u * v * v;
+
+                next_u.values_mut()[idx] =
+                    (u + self.diffusion_u * laplacian(&self.u, row, col) - reaction + self.feed * (1.0 - u)).clamp(0.0, 1.0);
+                next_v.values_mut()[idx] =
+                    (v + self.diffusion_v * laplacian(&self.v, row, col) + reaction - (self.kill + self.feed) * v).clamp(0.0, 1.0);
+            }
+        }
+
+        self.u = next_u;
+        self.v = next_v;
+    }
+
+    // each chemical's concentrations
+    pub fn u(&self) -> &FloatGrid {
+        &self.u
+    }
+
+    pub fn v(&self) -> &FloatGrid {
+        &self.v
+    }
+}
+
+// 3x3 laplacian: orthogonal neighbors weigh 0.2, diagonals 0.05, the center -1
+fn laplacian(grid: &FloatGrid, row: u32, col: u32) -> f32 {
+    let mut sum = -grid.get_wrapped(row, col, 0, 0);
+    for delta_row in -1..=1 {
+        for delta_col in -1..=1 {
+            let weight = match (delta_row, delta_col) {
+                (0, 0) => continue,
+                (0, _) | (_, 0) => 0.2,
+                _ => 0.05,
+            };
+            sum += weight * grid.get_wrapped(row, col, delta_row, delta_col);
+        }
+    }
+    sum
+}
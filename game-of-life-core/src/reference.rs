@@ -0,0 +1,136 @@
+// a deliberately simple stepper kept as an oracle for differential testing
+//
+// it shares nothing with the optimized tick except the rule tables: neighbors are found
+// with plain signed offsets and bounds checks, so a bug in the fast path (wrap
+// arithmetic, neighborhood packing, ...) shows up as a divergence
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::rules::TABLE_SIZE;
+use crate::{Cell, Universe};
+
+// where the fast path and the reference first disagreed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    pub generation: usize, // 1 is the first generation after the starting state
+    pub row: u32,
+    pub col: u32,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "diverged from the reference at generation {}, cell ({}, {})",
+            self.generation, self.row, self.col
+        )
+    }
+}
+
+impl core::error::Error for Divergence {}
+
+impl Universe {
+    // the next generation computed the slow, obvious way
+    pub fn reference_step(&self) -> Vec<Cell> {
+        let mut next = Vec::with_capacity(self.cells.len());
+
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let mut neighborhood = 0u16;
+
+                for delta_row in -1i64..=1 {
+                    for delta_col in -1i64..=1 {
+                        let mut neighbor_row = row as i64 + delta_row;
+                        let mut neighbor_col = col as i64 + delta_col;
+
+                        // crossing a twisted edge also moves along it, see twist.rs
+                        if neighbor_row < 0 || neighbor_row >= self.height as i64 {
+                            if !self.wrap_y {
+                                continue;
+                            }
+                            neighbor_col += neighbor_row.signum() * self.twist.1 as i64;
+                            neighbor_row = neighbor_row.rem_euclid(self.height as i64);
+                        }
+                        if neighbor_col < 0 || neighbor_col >= self.width as i64 {
+                            if !self.wrap_x {
+                                continue;
+                            }
+                            neighbor_row += neighbor_col.signum() * self.twist.0 as i64;
+                            neighbor_col = neighbor_col.rem_euclid(self.width as i64);
+                        }
+                        if neighbor_row < 0 || neighbor_row >= self.height as i64 {
+                            if !self.wrap_y {
+                                continue;
+                            }
+                            neighbor_row = neighbor_row.rem_euclid(self.height as i64);
+                        }
+
+                        let idx = (neighbor_row * self.width as i64 + neighbor_col) as usize;
+                        let alive = match self.cells[idx] {
+                            Cell::Alive => true,
+                            Cell::Wall => self.walls_alive,
+                            Cell::Dead => false,
+                        };
+                        if alive {
+                            let bit = (delta_row + 1) * 3 + (delta_col + 1);
+                            neighborhood |= 1 << bit;
+                        }
+                    }
+                }
+
+                // B0 rules: the cells are their difference from the background (dead cells past
+                // the edges included), which is alive on odd generations or for good with S8
+                let table = self.rule_table_at(row, col);
+                let full = TABLE_SIZE - 1;
+                let background = table[0] != 0 && (table[full] != 0 || self.b0_odd);
+                let (background, next_background) = match background {
+                    true => (full, table[full]),
+                    false => (0, table[0]),
+                };
+                let alive = table[neighborhood as usize ^ background] != next_background;
+
+                let idx = self.get_index(row, col);
+                next.push(match alive {
+                    _ if self.cells[idx] == Cell::Wall => Cell::Wall,
+                    false => Cell::Dead,
+                    true => Cell::Alive,
+                });
+            }
+        }
+
+        next
+    }
+
+    // one generation with reference_step instead of the fast path
+    pub(crate) fn reference_tick(&mut self) {
+        self.cells = self.reference_step();
+        self.b0_odd = !self.b0_odd;
+        // the age limit isn't part of the rule tables, it is applied the same way to both
+        self.update_ages();
+    }
+
+    // run the fast path and the reference side by side for some generations, starting from
+    // the current state (which is left untouched), and report the first disagreement
+    pub fn verify_against_reference(&self, generations: usize) -> Result<(), Divergence> {
+        let mut fast = self.clone();
+        // injections aren't part of the rules, the reference has no way to follow them
+        fast.injector = None;
+        let mut reference = self.clone();
+
+        for generation in 1..=generations {
+            fast.tick(1);
+            reference.reference_tick();
+
+            if let Some(idx) = fast.cells.iter().zip(&reference.cells).position(|(a, b)| a != b) {
+                return Err(Divergence {
+                    generation,
+                    row: idx as u32 / self.width,
+                    col: idx as u32 % self.width,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+// saving part of the grid, e.g. one object picked out of a soup to share on its own
+
+use alloc::vec::Vec;
+
+use crate::{Error, Universe};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Rle,       // see Universe::to_rle
+    Plaintext, // see Universe::to_plaintext
+    Bytes,     // the export_state layout, import_state reads it back
+}
+
+impl Universe {
+    // the rectangle between two corners (inclusive, in any order) as a universe of its own
+    // with the same rules and settings - corners past the edge follow the strictness setting
+    pub fn extract_region(&self, row0: u32, col0: u32, row1: u32, col1: u32) -> Result<Universe, Error> {
+        let first = self.checked_index(row0, col0)? as u32;
+        let second = self.checked_index(row1, col1)? as u32;
+        let (row0, col0) = (first / self.width, first % self.width);
+        let (row1, col1) = (second / self.width, second % self.width);
+        let (top, left) = (row0.min(row1), col0.min(col1));
+
+        let mut region = self.clone();
+        region.reframe(col0.max(col1) - left + 1, row0.max(row1) - top + 1, top as i64, left as i64);
+        Ok(region)
+    }
+
+    // the cells of the rectangle between two corners, as text for Rle and Plaintext
+    pub fn export_region(&self, row0: u32, col0: u32, row1: u32, col1: u32, format: ExportFormat) -> Result<Vec<u8>, Error> {
+        let region = self.extract_region(row0, col0, row1, col1)?;
+        Ok(match format {
+            ExportFormat::Rle => region.to_rle().into_bytes(),
+            ExportFormat::Plaintext => region.to_plaintext().into_bytes(),
+            ExportFormat::Bytes => region.export_state(),
+        })
+    }
+}
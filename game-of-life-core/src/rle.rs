@@ -0,0 +1,165 @@
+// RLE, the usual format for exchanging Life patterns
+//
+// an optional "x = 3, y = 3, rule = B3/S23" header and '#' comment lines, then runs such as
+// "2o3b$" - a count (1 when left out) followed by 'b' for dead cells, 'o' for live ones (other
+// letters are live too, for files from multistate rules), '$' to end a row, and '!' at the end
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::pattern::Pattern;
+use crate::rules::totalistic_rule_string;
+use crate::{Cell, Error, Universe};
+
+// longest line write_rle produces, as Golly does
+const LINE_LENGTH: usize = 70;
+// the most cells a grid sized by an RLE header can have, 4096 x 4096 - the text comes from
+// anywhere and the grid is allocated before it's filled
+pub(crate) const MAX_CELLS: u32 = 1 << 24;
+// how far a pattern without a header can reach along each axis, MAX_CELLS in all
+const MAX_SIDE: u32 = 1 << 12;
+
+impl Pattern {
+    pub fn from_rle(text: &str) -> Result<Pattern, Error> {
+        let mut cells = Vec::new();
+        read_rle(text, |row, col, _| {
+            cells.push((row, col));
+            Ok(())
+        })?;
+        Ok(Pattern::new(&cells))
+    }
+}
+
+impl Universe {
+    // the whole grid as RLE with the rule in the header when it's totalistic, walls are
+    // written as dead cells
+    pub fn to_rle(&self) -> String {
+        let rule = totalistic_rule_string(&self.rule_table);
+        write_rle(self.width, self.height, rule.as_deref(), 'b', |row, col| {
+            match self.cells[self.get_index(row, col)] {
+                Cell::Alive => 'o',
+                _ => 'b',
+            }
+        })
+    }
+}
+
+// call visit with the position and letter of every cell that isn't dead ('b' or '.'). live
+// cells past the header's size (or MAX_SIDE without one) are an error, found before a run
+// is expanded
+pub(crate) fn read_rle(text: &str, mut visit: impl FnMut(u32, u32, char) -> Result<(), Error>) -> Result<(), Error> {
+    let (width, height) = match rle_size(text) {
+        Some((width, height)) if width.checked_mul(height).is_some_and(|cells| cells <= MAX_CELLS) => (width, height),
+        Some((width, height)) => return Err(Error::TooLarge { width, height }),
+        None => (MAX_SIDE, MAX_SIDE),
+    };
+    let (mut row, mut col) = (0u32, 0u32);
+    let mut count: Option<u32> = None;
+
+    for line in text.lines().map(str::trim) {
+        if line.starts_with('#') || line.starts_with("x ") || line.starts_with("x=") {
+            continue;
+        }
+        for c in line.chars() {
+            let run = count.unwrap_or(1);
+            match c {
+                '0'..='9' => {
+                    let digit = c as u32 - '0' as u32;
+                    count = Some(count.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+                    continue;
+                }
+                'b' | '.' => col = col.saturating_add(run),
+                '$' => {
+                    row = row.saturating_add(run);
+                    col = 0;
+                }
+                '!' => return Ok(()),
+                c if c.is_ascii_alphabetic() => {
+                    let end = col.saturating_add(run);
+                    if run > 0 && (row >= height || end > width) {
+                        return Err(Error::OutOfBounds { row, col: end - 1 });
+                    }
+                    for col in col..end {
+                        visit(row, col, c)?;
+                    }
+                    col = col.saturating_add(run);
+                }
+                c if c.is_whitespace() => continue,
+                c => return Err(Error::UnexpectedChar { c, row, col }),
+            }
+            count = None;
+        }
+    }
+
+    Ok(())
+}
+
+// width and height from the "x = 3, y = 3" header, None without one
+pub(crate) fn rle_size(text: &str) -> Option<(u32, u32)> {
+    let header = text.lines().map(str::trim).find(|line| !line.starts_with('#'))?;
+    let mut size = (None, None);
+    for field in header.split(',') {
+        let (key, value) = field.split_once('=')?;
+        let value = value.trim().parse().ok();
+        match key.trim() {
+            "x" => size.0 = value,
+            "y" => size.1 = value,
+            _ => {}
+        }
+    }
+    Some((size.0?, size.1?))
+}
+
+// a width x height grid as RLE with a header, symbol gives the letter of every cell - dead is
+// the one for dead cells, left out at the end of rows
+pub(crate) fn write_rle(
+    width: u32,
+    height: u32,
+    rule: Option<&str>,
+    dead: char,
+    symbol: impl Fn(u32, u32) -> char,
+) -> String {
+    let mut runs: Vec<(u32, char)> = Vec::new();
+    let push = |runs: &mut Vec<(u32, char)>, c: char| match runs.last_mut() {
+        Some((count, last)) if *last == c => *count += 1,
+        _ => runs.push((1, c)),
+    };
+    for row in 0..height {
+        if row > 0 {
+            push(&mut runs, '$');
+        }
+        for col in 0..width {
+            push(&mut runs, symbol(row, col));
+        }
+        if runs.last().is_some_and(|&(_, c)| c == dead) {
+            runs.pop();
+        }
+    }
+    if runs.last().is_some_and(|&(_, c)| c == '$') {
+        runs.pop();
+    }
+
+    let mut rle = format!("x = {}, y = {}", width, height);
+    if let Some(rule) = rule {
+        rle.push_str(", rule = ");
+        rle.push_str(rule);
+    }
+    rle.push('\n');
+    let mut line = String::new();
+    let tokens = runs.iter().map(|&(count, c)| match count {
+        1 => format!("{}", c),
+        count => format!("{}{}", count, c),
+    });
+    for token in tokens.chain([String::from("!")]) {
+        if line.len() + token.len() > LINE_LENGTH {
+            rle.push_str(&line);
+            rle.push('\n');
+            line.clear();
+        }
+        line.push_str(&token);
+    }
+    rle.push_str(&line);
+    rle.push('\n');
+    rle
+}
@@ -0,0 +1,137 @@
+// rule lookup tables
+//
+// a cell's 3x3 neighborhood is packed into a 9-bit index, one bit per cell in row-major
+// order (top-left is bit 0, the cell itself is bit 4, bottom-right is bit 8). the table
+// holds the next state (0 or 1) of the center cell for every one of the 512 neighborhoods,
+// so a tick is a single lookup per cell no matter how complicated the rule is
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::Error;
+
+pub const TABLE_SIZE: usize = 512;
+
+// bit of the neighborhood index that holds the cell itself
+pub const CENTER_BIT: u16 = 1 << 4;
+
+pub type RuleTable = [u8; TABLE_SIZE];
+
+// build the table for a totalistic rule from its birth and survival neighbor counts
+pub fn totalistic_table(birth: &[u8], survival: &[u8]) -> RuleTable {
+    let mut table = [0; TABLE_SIZE];
+
+    for (neighborhood, next) in table.iter_mut().enumerate() {
+        let alive = neighborhood as u16 & CENTER_BIT != 0;
+        let live_neighbors = (neighborhood as u16 & !CENTER_BIT).count_ones() as u8;
+
+        let counts = if alive { survival } else { birth };
+        *next = counts.contains(&live_neighbors) as u8;
+    }
+
+    table
+}
+
+// the B/S rulestring for some birth and survival counts, e.g. "B36/S23"
+pub fn rule_string(birth: &[u8], survival: &[u8]) -> String {
+    let mut rule = String::from("B");
+    rule.extend(birth.iter().map(|&count| (b'0' + count) as char));
+    rule.push_str("/S");
+    rule.extend(survival.iter().map(|&count| (b'0' + count) as char));
+    rule
+}
+
+// the B/S rulestring a table was built from, None for tables that aren't totalistic
+pub fn totalistic_rule_string(table: &RuleTable) -> Option<String> {
+    let (mut birth, mut survival) = (Vec::new(), Vec::new());
+    for count in 0..=8u8 {
+        // the neighborhoods with count live neighbors that have the lowest bits set
+        let neighbors = (0..9).filter(|&bit| 1 << bit != CENTER_BIT).take(count as usize);
+        let dead = neighbors.fold(0, |neighborhood, bit| neighborhood | 1 << bit);
+        if table[dead as usize] != 0 {
+            birth.push(count);
+        }
+        if table[(dead | CENTER_BIT) as usize] != 0 {
+            survival.push(count);
+        }
+    }
+    match totalistic_table(&birth, &survival) == *table {
+        true => Some(rule_string(&birth, &survival)),
+        false => None,
+    }
+}
+
+// rules with B0 bring every empty neighborhood to life, so the background strobes between all
+// dead and all alive (or fills up for good with S8). the usual trick is to simulate the cells'
+// difference from the background instead: with S8 the complemented rule every generation,
+// without it two rules taking turns that both leave empty space empty. tables without B0
+// come back unchanged
+pub fn emulate_b0(table: &RuleTable, odd: bool) -> RuleTable {
+    if table[0] == 0 {
+        return *table;
+    }
+
+    let full = TABLE_SIZE - 1;
+    let mut emulated = [0; TABLE_SIZE];
+    for (neighborhood, next) in emulated.iter_mut().enumerate() {
+        *next = if table[full] != 0 {
+            1 - table[full ^ neighborhood]
+        } else if odd {
+            table[full ^ neighborhood]
+        } else {
+            1 - table[neighborhood]
+        };
+    }
+    emulated
+}
+
+// Conway's Game of Life, B3/S23:
+// any live cell with two or three live neighbours lives on, any dead cell with exactly
+// three live neighbours becomes a live cell, and every other cell dies or stays dead
+pub fn conway_table() -> RuleTable {
+    totalistic_table(&[3], &[2, 3])
+}
+
+// parse a B/S rulestring such as "B3/S23" or "b36/s23" into its rule table
+pub fn parse_rule(rule: &str) -> Result<RuleTable, Error> {
+    let mut birth = Vec::new();
+    let mut survival = Vec::new();
+
+    for part in rule.trim().split('/') {
+        let mut chars = part.chars();
+        let counts = match chars.next() {
+            Some('B') | Some('b') => &mut birth,
+            Some('S') | Some('s') => &mut survival,
+            _ => return Err(Error::InvalidRule(String::from(rule))),
+        };
+        for c in chars {
+            match c.to_digit(10) {
+                Some(count) if count <= 8 => counts.push(count as u8),
+                _ => {
+                    return Err(Error::InvalidNeighborCount {
+                        rule: String::from(rule),
+                        count: c,
+                    })
+                }
+            }
+        }
+    }
+
+    Ok(totalistic_table(&birth, &survival))
+}
+
+// a rectangular zone of the universe (inclusive bounds) that runs its own rule
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleRegion {
+    pub row0: u32,
+    pub col0: u32,
+    pub row1: u32,
+    pub col1: u32,
+    pub table: RuleTable,
+}
+
+impl RuleRegion {
+    pub fn contains(&self, row: u32, col: u32) -> bool {
+        row >= self.row0 && row <= self.row1 && col >= self.col0 && col <= self.col1
+    }
+}
@@ -0,0 +1,172 @@
+// drives a universe frame by frame, at a fixed number of generations per frame or adapting it
+// to hold a target frame rate as the pattern gets cheaper or more expensive to step
+//
+// the runner has no clock of its own: the host reports how long each frame took (ticking plus
+// rendering, without the idle time waiting for the next frame) through report_frame_time
+//
+// with a generation limit the runner stops on its own once the universe gets there, for
+// unattended runs that should end by themselves
+
+use crate::history::count_changes;
+use crate::hooks::GenerationStats;
+use crate::Universe;
+
+// weight of the newest frame in the running averages
+const SMOOTHING: f64 = 0.2;
+// most the rate changes by in one frame, so a single slow frame (a GC pause, a tab switch)
+// doesn't throw it off - the rate is set from the cost of a generation rather than scaled by
+// the frame time, so these only limit how fast it moves, nothing compounds
+const MAX_SPEEDUP: f64 = 1.25;
+const MAX_SLOWDOWN: f64 = 0.5;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Runner {
+    rate: f64, // generations per frame, fractional so slow adjustments add up
+    max_generations_per_frame: u32,
+    target_fps: Option<f64>,        // auto speed, None for a fixed rate
+    frame_ms: Option<f64>,          // running average of the reported frame times
+    generation_ms: Option<f64>,     // running average of frame time per generation in the frame
+    frame_generations: Option<u64>, // stepped by the last frame, until its time is reported
+    generation_limit: Option<u64>,
+    completed: Option<GenerationStats>, // the universe when the limit was reached
+}
+
+impl Default for Runner {
+    fn default() -> Runner {
+        Runner::new()
+    }
+}
+
+impl Runner {
+    // one generation per frame, auto speed off
+    pub fn new() -> Runner {
+        Runner {
+            rate: 1.0,
+            max_generations_per_frame: 1000,
+            target_fps: None,
+            frame_ms: None,
+            generation_ms: None,
+            frame_generations: None,
+            generation_limit: None,
+            completed: None,
+        }
+    }
+
+    // fixed speed, also where auto speed starts from
+    pub fn set_generations_per_frame(&mut self, generations: u32) {
+        self.rate = generations.clamp(1, self.max_generations_per_frame) as f64;
+    }
+
+    pub fn generations_per_frame(&self) -> u32 {
+        self.rate as u32
+    }
+
+    // cap for auto speed, and for set_generations_per_frame
+    pub fn set_max_generations_per_frame(&mut self, max: u32) {
+        self.max_generations_per_frame = max.max(1);
+        self.rate = self.rate.min(self.max_generations_per_frame as f64);
+    }
+
+    // adjust generations per frame to hold this frame rate, None (or anything not positive)
+    // goes back to a fixed rate
+    pub fn set_target_fps(&mut self, fps: Option<f64>) {
+        self.target_fps = fps.filter(|fps| *fps > 0.0);
+    }
+
+    pub fn target_fps(&self) -> Option<f64> {
+        self.target_fps
+    }
+
+    // average time of the recent frames, None before the first report
+    pub fn frame_ms(&self) -> Option<f64> {
+        self.frame_ms
+    }
+
+    // stop for good once the universe's generation count reaches limit (the last frame runs
+    // short to land on it exactly), None runs forever. setting it starts the runner over
+    pub fn set_generation_limit(&mut self, limit: Option<u64>) {
+        self.generation_limit = limit;
+        self.completed = None;
+    }
+
+    pub fn generation_limit(&self) -> Option<u64> {
+        self.generation_limit
+    }
+
+    // final stats once the limit has been reached, births and deaths from the last generation
+    pub fn completed(&self) -> Option<&GenerationStats> {
+        self.completed.as_ref()
+    }
+
+    // step the universe by one frame's worth of generations and return how many ran, 0 after
+    // the generation limit
+    pub fn frame(&mut self, universe: &mut Universe) -> u64 {
+        let generations = self.step(universe);
+        self.frame_generations = Some(generations);
+        generations
+    }
+
+    fn step(&mut self, universe: &mut Universe) -> u64 {
+        if self.completed.is_some() {
+            return 0;
+        }
+        let generations = self.generations_per_frame() as u64;
+        let remaining = match self.generation_limit {
+            Some(limit) => limit.saturating_sub(universe.generation()),
+            None => {
+                universe.advance(generations);
+                return generations;
+            }
+        };
+        if generations < remaining {
+            universe.advance(generations);
+            return generations;
+        }
+
+        // the last generation is stepped on its own to see what it changed
+        universe.advance(remaining.saturating_sub(1));
+        let before = universe.get_cells().to_vec();
+        if remaining > 0 {
+            universe.advance(1);
+        }
+        let (population, births, deaths) = count_changes(&before, universe.get_cells());
+        self.completed = Some(GenerationStats {
+            generation: universe.generation(),
+            population,
+            births,
+            deaths,
+        });
+        remaining
+    }
+
+    // how long the last frame took to tick and render, in milliseconds - a frame stepped
+    // without frame() is taken to have run generations_per_frame generations
+    pub fn report_frame_time(&mut self, ms: f64) {
+        let generations = self.frame_generations.take().unwrap_or(self.generations_per_frame() as u64);
+        if !ms.is_finite() || ms < 0.0 {
+            return;
+        }
+        self.frame_ms = Some(smooth(self.frame_ms, ms));
+        if generations == 0 {
+            return;
+        }
+        let cost = smooth(self.generation_ms, ms / generations as f64);
+        self.generation_ms = Some(cost);
+
+        if let Some(fps) = self.target_fps {
+            // as many generations as fit in the budget at the going cost, the rendering is in
+            // that cost too so the rate settles where whole frames fill the budget
+            let budget = 1000.0 / fps;
+            let rate = (budget / cost.max(0.001)).clamp(self.rate * MAX_SLOWDOWN, self.rate * MAX_SPEEDUP);
+            self.rate = rate.clamp(1.0, self.max_generations_per_frame as f64);
+        }
+    }
+}
+
+// running average with the newest value weighted by SMOOTHING
+fn smooth(average: Option<f64>, value: f64) -> f64 {
+    match average {
+        Some(average) => average + SMOOTHING * (value - average),
+        None => value,
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::random::Rng;
+
+#[repr(u8)] // one byte per cell, same as Cell, so JS can view the buffer as a Uint8Array
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Material {
+    Empty = 0,
+    Sand = 1,
+    Water = 2,
+    Wall = 3,
+    Fire = 4,
+}
+
+impl Material {
+    // whether a falling grain of `self` can swap places with `other`
+    fn sinks_through(self, other: Material) -> bool {
+        match self {
+            Material::Sand => other == Material::Empty || other == Material::Water,
+            Material::Water => other == Material::Empty,
+            _ => false,
+        }
+    }
+}
+
+// chance per tick that a fire cell burns out
+const FIRE_BURNOUT: f64 = 0.1;
+
+// falling-sand automaton: gravity pulls sand and water down, walls never move,
+// fire drifts upward, burns out, and is put out by water
+pub struct SandUniverse {
+    width: u32,
+    height: u32,
+    cells: Vec<Material>,
+}
+
+impl SandUniverse {
+    pub fn new(width: u32, height: u32) -> SandUniverse {
+        SandUniverse {
+            width,
+            height,
+            cells: vec![Material::Empty; (width * height) as usize],
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn get_material(&self, row: u32, col: u32) -> Material {
+        self.cells[self.get_index(row, col)]
+    }
+
+    pub fn set_material(&mut self, row: u32, col: u32, material: Material) {
+        let idx = self.get_index(row, col);
+        self.cells[idx] = material;
+    }
+
+    // fill a disc of the given radius around (row, col), clipped to the grid
+    pub fn paint(&mut self, row: u32, col: u32, radius: u32, material: Material) {
+        let r = radius as i64;
+        for delta_row in -r..=r {
+            for delta_col in -r..=r {
+                if delta_row * delta_row + delta_col * delta_col > r * r {
+                    continue;
+                }
+                let paint_row = row as i64 + delta_row;
+                let paint_col = col as i64 + delta_col;
+                if paint_row < 0 || paint_col < 0 || paint_row >= self.height as i64 || paint_col >= self.width as i64 {
+                    continue;
+                }
+                self.set_material(paint_row as u32, paint_col as u32, material);
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        for cell in self.cells.iter_mut() {
+            *cell = Material::Empty;
+        }
+    }
+
+    // move every particle once, bottom row first so falling grains don't move twice
+    pub fn tick<R: Rng>(&mut self, rng: &mut R) {
+        let mut moved = vec![false; self.cells.len()];
+
+        for row in (0..self.height).rev() {
+            for col in 0..self.width {
+                let idx = self.get_index(row, col);
+                if moved[idx] {
+                    continue;
+                }
+
+                let material = self.cells[idx];
+                // randomize which diagonal/side is tried first so piles stay symmetric
+                let side: i64 = if rng.gen_bool(0.5) { 1 } else { -1 };
+
+                let targets: &[(i64, i64)] = match material {
+                    Material::Sand => &[(1, 0), (1, -1), (1, 1)],
+                    Material::Water => &[(1, 0), (1, -1), (1, 1), (0, -1), (0, 1)],
+                    Material::Fire => {
+                        if rng.gen_bool(FIRE_BURNOUT) || self.touches(row, col, Material::Water) {
+                            self.cells[idx] = Material::Empty;
+                            continue;
+                        }
+                        &[(-1, 0), (-1, -1), (-1, 1)]
+                    }
+                    Material::Empty | Material::Wall => continue,
+                };
+
+                for &(delta_row, delta_col) in targets {
+                    let target = match self.offset(row, col, delta_row, delta_col * side) {
+                        Some(target) => target,
+                        None => continue,
+                    };
+                    let other = self.cells[target];
+                    let can_move = if material == Material::Fire {
+                        other == Material::Empty
+                    } else {
+                        material.sinks_through(other)
+                    };
+                    if can_move {
+                        self.cells.swap(idx, target);
+                        moved[target] = true;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_index(&self, row: u32, column: u32) -> usize {
+        (row * self.width + column) as usize
+    }
+
+    pub fn get_cells(&self) -> &[Material] {
+        &self.cells
+    }
+
+    // index of the cell at an offset, or None past the edge (the sandbox doesn't wrap)
+    fn offset(&self, row: u32, col: u32, delta_row: i64, delta_col: i64) -> Option<usize> {
+        let target_row = row as i64 + delta_row;
+        let target_col = col as i64 + delta_col;
+        if target_row < 0 || target_col < 0 || target_row >= self.height as i64 || target_col >= self.width as i64 {
+            return None;
+        }
+        Some(self.get_index(target_row as u32, target_col as u32))
+    }
+
+    // whether any of the 8 neighbors is made of the given material
+    fn touches(&self, row: u32, col: u32, material: Material) -> bool {
+        for delta_row in -1..=1 {
+            for delta_col in -1..=1 {
+                if let Some(idx) = self.offset(row, col, delta_row, delta_col) {
+                    if (delta_row, delta_col) != (0, 0) && self.cells[idx] == material {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,260 @@
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use crate::rules::{RuleRegion, RuleTable, TABLE_SIZE};
+use crate::{Cell, Error, Universe};
+
+// snapshot layout: width (u32, little endian), height (u32, little endian),
+// followed by one byte per cell in row-major order
+const HEADER_LEN: usize = 8;
+
+// full snapshots start with this, the last byte is the format version
+const FULL_MAGIC: [u8; 4] = *b"GoL\x02";
+// version 1 had a single byte of flags and nothing past the twist, it still loads
+const FULL_MAGIC_V1: [u8; 4] = *b"GoL\x01";
+// flag bits of a full snapshot
+const WRAP_X: u16 = 1;
+const WRAP_Y: u16 = 2;
+const HAS_AGES: u16 = 4;
+const WALLS_ALIVE: u16 = 8;
+const B0_ODD: u16 = 16;
+const MAX_AGE: u16 = 32;
+const TWISTED: u16 = 64;
+const HAS_LIFE_HISTORY: u16 = 128;
+const HAS_EXPOSURE: u16 = 256;
+
+impl Universe {
+    // build a universe from one byte per cell in row-major order, 2 is a wall and any other
+    // non-zero byte is alive
+    pub fn from_cells(width: u32, height: u32, cells: &[u8]) -> Result<Universe, Error> {
+        // the grid is indexed in u32, a size whose area overflows that can't match
+        let expected = width.checked_mul(height).map(|cells| cells as usize);
+        if expected != Some(cells.len()) {
+            return Err(Error::CellCountMismatch {
+                expected: expected.unwrap_or(usize::MAX),
+                got: cells.len(),
+            });
+        }
+
+        let mut universe = Universe::new(width, height);
+        universe.cells = cells_from_bytes(cells);
+        Ok(universe)
+    }
+
+    // serialize the universe into a flat byte array that can be stored or shared
+    pub fn export_state(&self) -> Vec<u8> {
+        let mut state = Vec::with_capacity(HEADER_LEN + self.cells.len());
+        state.extend_from_slice(&self.width.to_le_bytes());
+        state.extend_from_slice(&self.height.to_le_bytes());
+        state.extend(self.cells.iter().map(|&cell| cell as u8));
+        state
+    }
+
+    // replace the universe (dimensions included) with a previously exported state
+    pub fn import_state(&mut self, state: &[u8]) -> Result<(), Error> {
+        if state.len() < HEADER_LEN {
+            return Err(Error::SnapshotTruncated);
+        }
+
+        let width = u32::from_le_bytes([state[0], state[1], state[2], state[3]]);
+        let height = u32::from_le_bytes([state[4], state[5], state[6], state[7]]);
+        let body = &state[HEADER_LEN..];
+
+        // the header is untrusted, its area can overflow
+        if width.checked_mul(height).is_none_or(|cells| body.len() != cells as usize) {
+            return Err(Error::SnapshotSizeMismatch);
+        }
+
+        self.width = width;
+        self.height = height;
+        self.replace_cells(cells_from_bytes(body));
+
+        Ok(())
+    }
+}
+
+// full snapshot layout, all integers little endian:
+//   magic "GoL" + version 2, width u32, height u32, generation u64, flags u16 (WRAP_X, WRAP_Y,
+//   HAS_AGES, WALLS_ALIVE, B0_ODD, MAX_AGE, TWISTED, HAS_LIFE_HISTORY, HAS_EXPOSURE), rule
+//   table (512 bytes), region count u32 then per region row0, col0, row1, col1 (u32 each) and
+//   its table (512 bytes), one byte per cell, and with HAS_AGES one u16 age per cell, with
+//   MAX_AGE the age limit (u16), with TWISTED the row and column shifts (i32 each), with
+//   HAS_LIFE_HISTORY one byte of LifeHistory flags per cell and with HAS_EXPOSURE one u32
+//   exposure count per cell followed by the exposed generations (u32)
+impl Universe {
+    // everything needed to carry on the simulation elsewhere (cells, ages, rules, wrapping, the
+    // generation count, the LifeHistory envelope and the exposure) as one byte array, e.g. to transfer a running simulation to a worker
+    pub fn take_snapshot_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&FULL_MAGIC);
+        bytes.extend_from_slice(&self.width.to_le_bytes());
+        bytes.extend_from_slice(&self.height.to_le_bytes());
+        bytes.extend_from_slice(&self.generation.to_le_bytes());
+
+        let mut flags = 0;
+        if self.wrap_x {
+            flags |= WRAP_X;
+        }
+        if self.wrap_y {
+            flags |= WRAP_Y;
+        }
+        if self.ages.is_some() {
+            flags |= HAS_AGES;
+        }
+        if self.walls_alive {
+            flags |= WALLS_ALIVE;
+        }
+        if self.b0_odd {
+            flags |= B0_ODD;
+        }
+        if self.max_age > 0 {
+            flags |= MAX_AGE;
+        }
+        if self.is_twisted() {
+            flags |= TWISTED;
+        }
+        if self.life_history.is_some() {
+            flags |= HAS_LIFE_HISTORY;
+        }
+        if self.exposure.is_some() {
+            flags |= HAS_EXPOSURE;
+        }
+        bytes.extend_from_slice(&flags.to_le_bytes());
+
+        bytes.extend_from_slice(&self.rule_table);
+        bytes.extend_from_slice(&(self.regions.len() as u32).to_le_bytes());
+        for region in &self.regions {
+            for bound in [region.row0, region.col0, region.row1, region.col1] {
+                bytes.extend_from_slice(&bound.to_le_bytes());
+            }
+            bytes.extend_from_slice(&region.table);
+        }
+
+        bytes.extend(self.cells.iter().map(|&cell| cell as u8));
+        if let Some(ages) = &self.ages {
+            for age in ages {
+                bytes.extend_from_slice(&age.to_le_bytes());
+            }
+        }
+        if self.max_age > 0 {
+            bytes.extend_from_slice(&self.max_age.to_le_bytes());
+        }
+        if self.is_twisted() {
+            bytes.extend_from_slice(&self.twist.0.to_le_bytes());
+            bytes.extend_from_slice(&self.twist.1.to_le_bytes());
+        }
+        if let Some(history) = &self.life_history {
+            bytes.extend_from_slice(history);
+        }
+        if let Some(exposure) = &self.exposure {
+            for count in exposure {
+                bytes.extend_from_slice(&count.to_le_bytes());
+            }
+            bytes.extend_from_slice(&self.exposed_generations.to_le_bytes());
+        }
+        bytes
+    }
+
+    // rebuild a universe from take_snapshot_bytes
+    pub fn from_snapshot_bytes(bytes: &[u8]) -> Result<Universe, Error> {
+        let mut reader = Reader { bytes };
+        let magic = reader.take(FULL_MAGIC.len())?;
+        if magic != FULL_MAGIC && magic != FULL_MAGIC_V1 {
+            return Err(Error::UnknownSnapshotFormat);
+        }
+
+        let width = reader.u32()?;
+        let height = reader.u32()?;
+        let generation = u64::from_le_bytes(reader.take(8)?.try_into().unwrap());
+        let flags = match magic == FULL_MAGIC_V1 {
+            true => reader.take(1)?[0] as u16,
+            false => u16::from_le_bytes(reader.take(2)?.try_into().unwrap()),
+        };
+        let rule_table = reader.table()?;
+
+        let mut regions = Vec::new();
+        for _ in 0..reader.u32()? {
+            let (row0, col0, row1, col1) = (reader.u32()?, reader.u32()?, reader.u32()?, reader.u32()?);
+            regions.push(RuleRegion {
+                row0,
+                col0,
+                row1,
+                col1,
+                table: reader.table()?,
+            });
+        }
+
+        let cells = width.checked_mul(height).ok_or(Error::SnapshotSizeMismatch)?;
+        let mut universe = Universe::from_cells(width, height, reader.take(cells as usize)?)?;
+        universe.generation = generation;
+        universe.set_wrap(flags & WRAP_X != 0, flags & WRAP_Y != 0);
+        universe.rule_table = rule_table;
+        universe.regions = regions;
+        universe.walls_alive = flags & WALLS_ALIVE != 0;
+        universe.b0_odd = flags & B0_ODD != 0;
+        if flags & HAS_AGES != 0 {
+            let ages = reader.take(universe.cells.len() * 2)?;
+            universe.ages = Some(ages.chunks_exact(2).map(|age| u16::from_le_bytes([age[0], age[1]])).collect());
+        }
+        if flags & MAX_AGE != 0 {
+            let max_age = reader.take(2)?;
+            universe.max_age = u16::from_le_bytes([max_age[0], max_age[1]]);
+        }
+        if flags & TWISTED != 0 {
+            let (row_shift, col_shift) = (reader.u32()? as i32, reader.u32()? as i32);
+            universe.set_twist(row_shift, col_shift).map_err(|_| Error::UnknownSnapshotFormat)?;
+        }
+        if flags & HAS_LIFE_HISTORY != 0 {
+            universe.life_history = Some(reader.take(universe.cells.len())?.to_vec());
+        }
+        if flags & HAS_EXPOSURE != 0 {
+            let exposure = reader.take(universe.cells.len() * 4)?;
+            universe.exposure = Some(exposure.chunks_exact(4).map(|count| u32::from_le_bytes(count.try_into().unwrap())).collect());
+            universe.exposed_generations = reader.u32()?;
+        }
+
+        if !reader.bytes.is_empty() {
+            return Err(Error::SnapshotSizeMismatch);
+        }
+        Ok(universe)
+    }
+}
+
+// pulls fields off the front of a full snapshot
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.bytes.len() < len {
+            return Err(Error::SnapshotTruncated);
+        }
+        let (head, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(head)
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn table(&mut self) -> Result<RuleTable, Error> {
+        let mut table: RuleTable = [0; TABLE_SIZE];
+        for (entry, &next) in table.iter_mut().zip(self.take(TABLE_SIZE)?) {
+            *entry = (next != 0) as u8;
+        }
+        Ok(table)
+    }
+}
+
+fn cells_from_bytes(bytes: &[u8]) -> Vec<Cell> {
+    bytes
+        .iter()
+        .map(|&byte| match byte {
+            0 => Cell::Dead,
+            2 => Cell::Wall,
+            _ => Cell::Alive,
+        })
+        .collect()
+}
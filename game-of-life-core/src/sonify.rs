@@ -0,0 +1,72 @@
+// compact per-tick activity summary for driving audio: births and deaths per band of
+// rows or columns
+
+use alloc::vec;
+
+use crate::{Cell, Universe};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    Rows,    // bands are horizontal strips, band 0 at the top
+    Columns, // bands are vertical strips, band 0 on the left
+}
+
+impl Universe {
+    // split the grid into bands along axis and summarize every tick's births and deaths
+    // per band, 0 bands turns it off
+    pub fn set_sonification(&mut self, bands: u32, axis: Axis) {
+        self.sonification = if bands == 0 { None } else { Some((bands, axis)) };
+        self.sound.clear();
+    }
+
+    // births per band followed by deaths per band for the last tick, each normalized to
+    // 0-1 by the number of cells in the band - empty when sonification is off
+    pub fn sound_data(&self) -> &[f32] {
+        &self.sound
+    }
+
+    // called at the end of every tick with the cells from before it
+    pub(crate) fn update_sound(&mut self, before: &[Cell]) {
+        let (bands, axis) = match self.sonification {
+            Some(config) => config,
+            None => return,
+        };
+        let length = match axis {
+            Axis::Rows => self.height,
+            Axis::Columns => self.width,
+        };
+
+        let mut births = vec![0u32; bands as usize];
+        let mut deaths = vec![0u32; bands as usize];
+        for (idx, (&old, &new)) in before.iter().zip(&self.cells).enumerate() {
+            if old == new {
+                continue;
+            }
+            let position = match axis {
+                Axis::Rows => idx as u32 / self.width,
+                Axis::Columns => idx as u32 % self.width,
+            };
+            let band = ((position as u64 * bands as u64) / length as u64) as usize;
+            match new {
+                Cell::Alive => births[band] += 1,
+                Cell::Dead => deaths[band] += 1,
+                // walls only change through edits, never during a tick
+                Cell::Wall => {}
+            }
+        }
+
+        // the number of cells in each band, bands differ by at most one row or column
+        let across = (self.cells.len() as u32 / length.max(1)) as f32;
+        let band_size = |band: u32| {
+            let start = (band as u64 * length as u64).div_ceil(bands as u64);
+            let end = ((band + 1) as u64 * length as u64).div_ceil(bands as u64);
+            ((end - start) as f32 * across).max(1.0)
+        };
+        self.sound = births
+            .iter()
+            .chain(&deaths)
+            .enumerate()
+            .map(|(i, &count)| count as f32 / band_size(i as u32 % bands))
+            .collect();
+    }
+}
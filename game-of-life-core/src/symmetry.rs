@@ -0,0 +1,28 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry {
+    None = 0,
+    Horizontal = 1, // mirrored left to right
+    Vertical = 2,   // mirrored top to bottom
+    Rotate180 = 3,  // point symmetric around the center
+    FourFold = 4,   // mirrored both left to right and top to bottom
+}
+
+impl Symmetry {
+    // every cell that (row, col) maps to under this symmetry, itself included
+    // (may contain duplicates for cells on an axis of symmetry)
+    pub fn images(self, row: u32, col: u32, width: u32, height: u32) -> Vec<(u32, u32)> {
+        let mirror_row = height - 1 - row;
+        let mirror_col = width - 1 - col;
+
+        match self {
+            Symmetry::None => vec![(row, col)],
+            Symmetry::Horizontal => vec![(row, col), (row, mirror_col)],
+            Symmetry::Vertical => vec![(row, col), (mirror_row, col)],
+            Symmetry::Rotate180 => vec![(row, col), (mirror_row, mirror_col)],
+            Symmetry::FourFold => vec![(row, col), (row, mirror_col), (mirror_row, col), (mirror_row, mirror_col)],
+        }
+    }
+}
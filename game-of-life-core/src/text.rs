@@ -0,0 +1,114 @@
+// plain text views of the universe
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Cell, Error, Universe};
+
+// braille patterns start at U+2800, each of the 8 dots is one bit of the offset
+const BRAILLE_BASE: u32 = 0x2800;
+// bit for the dot at [row][col] of a 4 row x 2 column braille cell
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+// walls in ASCII art, whatever the live and dead characters are
+const WALL: char = '#';
+
+impl Universe {
+    // parse ASCII art with '.' for dead, 'O' for alive and '#' for wall cells, one row per
+    // line, e.g.
+    //
+    //     .O.
+    //     ..O
+    //     OOO
+    //
+    // the width is the longest line (shorter lines are padded with dead cells), blank lines
+    // around the pattern and leading indentation shared by all lines are ignored, and lines
+    // starting with '!' are comments as in the plaintext .cells format
+    pub fn from_string(text: &str) -> Result<Universe, Error> {
+        Universe::from_string_with(text, 'O', '.')
+    }
+
+    // same as from_string with custom characters for live and dead cells
+    pub fn from_string_with(text: &str, alive: char, dead: char) -> Result<Universe, Error> {
+        let lines: Vec<&str> = text
+            .lines()
+            .map(|line| line.trim_end())
+            .filter(|line| !line.trim_start().starts_with('!'))
+            .collect();
+        // drop blank lines before and after the pattern, but keep the ones inside it
+        let first = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+        let last = lines.iter().rposition(|line| !line.is_empty()).map_or(first, |last| last + 1);
+        let lines = &lines[first..last];
+
+        let indent = lines
+            .iter()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().take_while(|c| c.is_whitespace()).count())
+            .min()
+            .unwrap_or(0);
+        let width = lines.iter().map(|line| line.chars().count().saturating_sub(indent)).max().unwrap_or(0);
+
+        let mut universe = Universe::new(width as u32, lines.len() as u32);
+        for (row, line) in lines.iter().enumerate() {
+            for (col, c) in line.chars().skip(indent).enumerate() {
+                let state = match c {
+                    c if c == alive => Cell::Alive,
+                    c if c == dead => Cell::Dead,
+                    WALL => Cell::Wall,
+                    _ => {
+                        return Err(Error::UnexpectedChar {
+                            c,
+                            row: row as u32,
+                            col: col as u32,
+                        })
+                    }
+                };
+                universe.set_cell(row as u32, col as u32, state);
+            }
+        }
+
+        Ok(universe)
+    }
+
+    // the grid as plaintext (.cells) in the from_string format, one line per row with trailing
+    // dead cells left out
+    pub fn to_plaintext(&self) -> String {
+        let mut text = String::new();
+        for row in self.cells.chunks(self.width.max(1) as usize) {
+            let line: String = row
+                .iter()
+                .map(|&cell| match cell {
+                    Cell::Dead => '.',
+                    Cell::Alive => 'O',
+                    Cell::Wall => WALL,
+                })
+                .collect();
+            text.push_str(line.trim_end_matches('.'));
+            text.push('\n');
+        }
+        text
+    }
+
+    // compact text view packing 2x4 blocks of cells into one braille character each, one line
+    // per 4 rows - cells past the edge in the last blocks count as dead
+    pub fn render_braille(&self) -> String {
+        let mut text = String::new();
+        for block_row in (0..self.height).step_by(4) {
+            if block_row > 0 {
+                text.push('\n');
+            }
+            for block_col in (0..self.width).step_by(2) {
+                let mut dots = 0;
+                for (dr, row_dots) in BRAILLE_DOTS.iter().enumerate() {
+                    for (dc, &dot) in row_dots.iter().enumerate() {
+                        if self.get_cell(block_row + dr as u32, block_col + dc as u32) == Some(Cell::Alive) {
+                            dots |= dot;
+                        }
+                    }
+                }
+                // every offset below 0x100 is an assigned braille pattern
+                text.push(char::from_u32(BRAILLE_BASE + dots).unwrap());
+            }
+        }
+        text
+    }
+}
@@ -0,0 +1,69 @@
+// operations that change the shape of the universe
+
+use alloc::vec;
+
+use crate::rules::RuleRegion;
+use crate::{Cell, Universe};
+
+impl Universe {
+    // resize to new_width x new_height, moving the old cell at (row, col) to
+    // (row - row_offset, col - col_offset). cells and rule regions that land outside are dropped
+    pub fn reframe(&mut self, new_width: u32, new_height: u32, row_offset: i64, col_offset: i64) {
+        let mut cells = vec![Cell::Dead; (new_width * new_height) as usize];
+
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let new_row = row as i64 - row_offset;
+                let new_col = col as i64 - col_offset;
+                if new_row < 0 || new_col < 0 || new_row >= new_height as i64 || new_col >= new_width as i64 {
+                    continue;
+                }
+                cells[(new_row * new_width as i64 + new_col) as usize] = self.cells[self.get_index(row, col)];
+            }
+        }
+
+        // shift the rule regions the same way, clipping them to the new bounds
+        let clamp = |value: i64, size: u32| value.clamp(0, size as i64 - 1) as u32;
+        self.regions = self
+            .regions
+            .drain(..)
+            .filter_map(|region| {
+                let row0 = region.row0 as i64 - row_offset;
+                let col0 = region.col0 as i64 - col_offset;
+                let row1 = region.row1 as i64 - row_offset;
+                let col1 = region.col1 as i64 - col_offset;
+                if row1 < 0 || col1 < 0 || row0 >= new_height as i64 || col0 >= new_width as i64 {
+                    return None;
+                }
+                Some(RuleRegion {
+                    row0: clamp(row0, new_height),
+                    col0: clamp(col0, new_width),
+                    row1: clamp(row1, new_height),
+                    col1: clamp(col1, new_width),
+                    table: region.table,
+                })
+            })
+            .collect();
+
+        self.width = new_width;
+        self.height = new_height;
+        self.replace_cells(cells);
+    }
+
+    // shrink-wrap the universe to the live cells plus margin dead cells on every side,
+    // returns false (and leaves the universe alone) when there is nothing alive to crop to
+    pub fn crop_to_pattern(&mut self, margin: u32) -> bool {
+        let bounds = match self.bounding_box() {
+            Some(bounds) => bounds,
+            None => return false,
+        };
+
+        self.reframe(
+            bounds.width() + 2 * margin,
+            bounds.height() + 2 * margin,
+            bounds.min_row as i64 - margin as i64,
+            bounds.min_col as i64 - margin as i64,
+        );
+        true
+    }
+}
@@ -0,0 +1,72 @@
+// twisted tori: a wraparound that lands some rows or columns further along, so oscillators
+// and spaceship collisions that only close up on shifted boundaries can be studied
+//
+// like Golly, only one of the two shifts can be non-zero at a time - with both the corners
+// would depend on which edge is crossed first. a shift only applies while its edge wraps
+
+use crate::{Error, Universe};
+
+impl Universe {
+    // row_shift: rows moved down when crossing the right edge (and up crossing the left one),
+    // col_shift: columns moved right when crossing the bottom edge (and left crossing the top)
+    pub fn set_twist(&mut self, row_shift: i32, col_shift: i32) -> Result<(), Error> {
+        if row_shift != 0 && col_shift != 0 {
+            return Err(Error::DoubleTwist);
+        }
+        self.twist = (row_shift, col_shift);
+        Ok(())
+    }
+
+    // (row_shift, col_shift), (0, 0) for a plain torus
+    pub fn twist(&self) -> (i32, i32) {
+        self.twist
+    }
+
+    pub(crate) fn is_twisted(&self) -> bool {
+        self.twist != (0, 0)
+    }
+
+    // the cell at (row + dr, col + dc) across any wrapping (and twisted) edge, None past an
+    // edge that doesn't wrap
+    pub(crate) fn neighbor(&self, row: u32, col: u32, dr: i64, dc: i64) -> Option<(u32, u32)> {
+        let (height, width) = (self.height as i64, self.width as i64);
+        let (row_shift, col_shift) = (self.twist.0 as i64, self.twist.1 as i64);
+        let (mut row, mut col) = (row as i64 + dr, col as i64 + dc);
+
+        if row < 0 || row >= height {
+            if !self.wrap_y {
+                return None;
+            }
+            col += if row < 0 { -col_shift } else { col_shift };
+            row = row.rem_euclid(height);
+        }
+        if col < 0 || col >= width {
+            if !self.wrap_x {
+                return None;
+            }
+            row += if col < 0 { -row_shift } else { row_shift };
+            col = col.rem_euclid(width);
+            // a row shift can carry the cell past the top or bottom in turn
+            if row < 0 || row >= height {
+                if !self.wrap_y {
+                    return None;
+                }
+                row = row.rem_euclid(height);
+            }
+        }
+        Some((row as u32, col as u32))
+    }
+
+    // neighborhood for a twisted universe, one neighbor lookup per bit
+    pub(crate) fn twisted_neighborhood(&self, row: u32, col: u32) -> u16 {
+        let mut neighborhood = 0;
+        for (i, dr) in (-1..=1).enumerate() {
+            for (j, dc) in (-1..=1).enumerate() {
+                if let Some((row, col)) = self.neighbor(row, col, dr, dc) {
+                    neighborhood |= (self.counts_as_alive(self.cells[self.get_index(row, col)]) as u16) << (i * 3 + j);
+                }
+            }
+        }
+        neighborhood
+    }
+}
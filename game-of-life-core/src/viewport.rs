@@ -0,0 +1,75 @@
+// camera over the universe, converting between screen pixels and cell coordinates
+// the engine doesn't draw anything itself, frontends use this to decide what to draw where
+
+// zoom limits in pixels per cell
+const MIN_SCALE: f64 = 0.125;
+const MAX_SCALE: f64 = 128.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Viewport {
+    x: f64,     // column at the left edge of the screen
+    y: f64,     // row at the top edge of the screen
+    scale: f64, // pixels per cell
+}
+
+impl Default for Viewport {
+    fn default() -> Viewport {
+        Viewport::new()
+    }
+}
+
+impl Viewport {
+    // top-left cell at the top-left of the screen, one pixel per cell
+    pub fn new() -> Viewport {
+        Viewport {
+            x: 0.0,
+            y: 0.0,
+            scale: 1.0,
+        }
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    // put cell (y, x) at the top-left of the screen, scale is clamped to the zoom limits
+    pub fn set_viewport(&mut self, x: f64, y: f64, scale: f64) {
+        self.x = x;
+        self.y = y;
+        self.scale = scale.clamp(MIN_SCALE, MAX_SCALE);
+    }
+
+    // drag the view by (dx, dy) screen pixels, the cells move along with the pointer
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        self.x -= dx / self.scale;
+        self.y -= dy / self.scale;
+    }
+
+    // zoom by factor around the screen point (px, py), the cell under it stays put
+    pub fn zoom_at(&mut self, px: f64, py: f64, factor: f64) {
+        let (col, row) = (self.x + px / self.scale, self.y + py / self.scale);
+        self.scale = (self.scale * factor).clamp(MIN_SCALE, MAX_SCALE);
+        self.x = col - px / self.scale;
+        self.y = row - py / self.scale;
+    }
+
+    // (row, col) of the cell under the screen point, may be outside the universe
+    pub fn screen_to_cell(&self, px: f64, py: f64) -> (i64, i64) {
+        let row = libm::floor(self.y + py / self.scale) as i64;
+        let col = libm::floor(self.x + px / self.scale) as i64;
+        (row, col)
+    }
+
+    // screen position (px, py) of the top-left corner of a cell
+    pub fn cell_to_screen(&self, row: i64, col: i64) -> (f64, f64) {
+        ((col as f64 - self.x) * self.scale, (row as f64 - self.y) * self.scale)
+    }
+}
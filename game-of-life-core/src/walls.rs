@@ -0,0 +1,38 @@
+// wall cells, obstacles that never change for building mazes and containment chambers
+//
+// walls are painted like any other state (set_cell, paint, draw_line, ...) and are kept by
+// ticks, randomize and die - only edits and clear_walls remove them
+
+use crate::{Cell, Universe};
+
+impl Universe {
+    // walls count as dead neighbors by default, alive makes them feed births and survival
+    // along their edges
+    pub fn set_walls_alive(&mut self, alive: bool) {
+        self.walls_alive = alive;
+    }
+
+    pub fn walls_alive(&self) -> bool {
+        self.walls_alive
+    }
+
+    // whether a cell in this state is a live neighbor
+    pub(crate) fn counts_as_alive(&self, cell: Cell) -> bool {
+        match cell {
+            Cell::Alive => true,
+            Cell::Wall => self.walls_alive,
+            Cell::Dead => false,
+        }
+    }
+
+    pub fn wall_count(&self) -> usize {
+        self.cells.iter().filter(|&&cell| cell == Cell::Wall).count()
+    }
+
+    // turn every wall back into a dead cell
+    pub fn clear_walls(&mut self) {
+        for cell in self.cells.iter_mut().filter(|cell| **cell == Cell::Wall) {
+            *cell = Cell::Dead;
+        }
+    }
+}
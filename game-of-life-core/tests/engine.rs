@@ -0,0 +1,1204 @@
+//! Native test suite for the engine - no browser needed.
+
+use game_of_life_core::age::Palette;
+use game_of_life_core::bounds::Strictness;
+use game_of_life_core::injector::{Injection, Injector};
+use game_of_life_core::pattern::{Pattern, Transform};
+use game_of_life_core::presets::FillPreset;
+use game_of_life_core::runner::Runner;
+use game_of_life_core::sonify::Axis;
+use game_of_life_core::symmetry::Symmetry;
+use game_of_life_core::viewport::Viewport;
+use game_of_life_core::{Cell, Error, Universe};
+
+// only get compiled when running test - helper functions
+
+// create a starting spaceship pattern
+pub fn input_spaceship() -> Universe {
+    let mut universe = Universe::new(6, 6);
+    universe.set_cells(&[(1, 2), (2, 3), (3, 1), (3, 2), (3, 3)]);
+    universe
+}
+
+// check positon of cells after one tick (manually calculated)
+pub fn expected_spaceship() -> Universe {
+    let mut universe = Universe::new(6, 6);
+    universe.set_cells(&[(2, 1), (2, 3), (3, 2), (3, 3), (4, 2)]);
+    universe
+}
+
+// test
+
+#[test]
+pub fn test_tick() {
+    let mut universe = input_spaceship();
+    universe.tick(1);
+
+    assert_eq!(universe.get_cells(), expected_spaceship().get_cells());
+}
+
+#[test]
+pub fn test_glider_returns_after_full_lap() {
+    // a glider moves one cell diagonally every 4 generations, so 24 generations on a
+    // 6x6 torus bring it back to where it started
+    let mut universe = input_spaceship();
+    universe.tick(24);
+
+    assert_eq!(universe.get_cells(), input_spaceship().get_cells());
+}
+
+#[test]
+pub fn test_state_round_trip() {
+    let universe = input_spaceship();
+    let mut restored = Universe::new(2, 2);
+    restored.import_state(&universe.export_state()).unwrap();
+
+    assert_eq!(restored.get_cells(), universe.get_cells());
+    assert!(restored.import_state(&[1, 0, 0, 0, 1, 0, 0, 0]).is_err());
+}
+
+#[test]
+pub fn test_get_cell() {
+    let universe = input_spaceship();
+
+    assert_eq!(universe.get_cell(3, 1), Some(Cell::Alive));
+    assert_eq!(universe.get_cell(6, 6), None);
+}
+
+#[test]
+pub fn test_fast_path_matches_reference() {
+    // cover every wrap combination and a rule region on a random soup
+    let mut universe = Universe::new(13, 9);
+    let mut seed = 0x9e37_79b9u32;
+    for row in 0..9 {
+        for col in 0..13 {
+            // cheap xorshift so the soup is the same on every run
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            if seed.is_multiple_of(3) {
+                universe.set_cell(row, col, Cell::Alive);
+            }
+        }
+    }
+    universe.add_rule_region(2, 2, 6, 8, "B36/S23").unwrap();
+
+    for &(x, y) in [(true, true), (true, false), (false, true), (false, false)].iter() {
+        universe.set_wrap(x, y);
+        assert_eq!(universe.verify_against_reference(30), Ok(()));
+    }
+}
+
+#[test]
+pub fn test_bounding_box() {
+    let bounds = input_spaceship().bounding_box().unwrap();
+
+    assert_eq!((bounds.min_row, bounds.min_col, bounds.max_row, bounds.max_col), (1, 1, 3, 3));
+    assert_eq!(Universe::new(4, 4).bounding_box(), None);
+}
+
+#[test]
+pub fn test_crop_to_pattern() {
+    let mut universe = Universe::new(20, 30);
+    universe.set_cells(&[(10, 5), (11, 6), (12, 4), (12, 5), (12, 6)]);
+
+    assert!(universe.crop_to_pattern(1));
+    assert_eq!((universe.width(), universe.height()), (5, 5));
+    assert_eq!(universe.get_cells(), {
+        let mut expected = Universe::new(5, 5);
+        expected.set_cells(&[(1, 2), (2, 3), (3, 1), (3, 2), (3, 3)]);
+        expected
+    }
+    .get_cells());
+    assert!(!Universe::new(3, 3).crop_to_pattern(1));
+}
+
+#[test]
+pub fn test_measure_velocity() {
+    let glider = input_spaceship();
+    let velocity = glider.measure_velocity(10).unwrap();
+    assert_eq!((velocity.period, velocity.dx, velocity.dy), (4, 1, 1));
+    assert_eq!(velocity.label(), "c/4 diagonal");
+
+    let mut blinker = Universe::new(5, 5);
+    blinker.set_cells(&[(2, 1), (2, 2), (2, 3)]);
+    assert_eq!(blinker.measure_velocity(10).unwrap().label(), "oscillator p2");
+}
+
+#[test]
+pub fn test_density_and_entropy() {
+    let mut universe = Universe::new(4, 4);
+    assert_eq!(universe.density(), 0.0);
+    assert_eq!(universe.block_entropy(2), Ok(0.0));
+
+    // two of the four 2x2 blocks are full, the other two empty - one bit of entropy
+    universe.set_cells(&[(0, 0), (0, 1), (1, 0), (1, 1), (2, 2), (2, 3), (3, 2), (3, 3)]);
+    assert_eq!(universe.population(), 8);
+    assert_eq!(universe.density(), 0.5);
+    assert_eq!(universe.block_entropy(2), Ok(1.0));
+    assert!(universe.block_entropy(0).is_err());
+
+    // a wall is dead, not a bit that spills into its neighbor's
+    let universe = Universe::from_string("O..#\n....").unwrap();
+    assert_eq!(universe.block_entropy(2), Ok(1.0));
+}
+
+#[test]
+pub fn test_measure_lifespan() {
+    // an L tromino becomes a block after one generation
+    let mut universe = Universe::new(4, 4);
+    universe.set_cells(&[(1, 1), (1, 2), (2, 1)]);
+    let lifespan = universe.measure_lifespan(200).unwrap();
+    assert_eq!((lifespan.generations, lifespan.period, lifespan.population), (1, 1, 4));
+
+    // a beacon (8, 6, 8, 6... cells) is periodic from the start
+    let mut universe = Universe::new(8, 8);
+    universe.set_cells(&[(1, 1), (1, 2), (2, 1), (2, 2), (3, 3), (3, 4), (4, 3), (4, 4)]);
+    let lifespan = universe.measure_lifespan(200).unwrap();
+    assert_eq!((lifespan.generations, lifespan.period, lifespan.population), (0, 2, 8));
+
+    // a glider is stable from the start, and has to escape the 6x6 grid without crashing
+    // into the edge to still have five cells at the end
+    let lifespan = input_spaceship().measure_lifespan(200).unwrap();
+    assert_eq!((lifespan.generations, lifespan.population), (0, 5));
+    assert_eq!(input_spaceship().measure_lifespan(50), None);
+}
+
+#[test]
+pub fn test_viewport() {
+    let mut viewport = Viewport::new();
+    viewport.set_viewport(10.0, 20.0, 4.0);
+    assert_eq!(viewport.screen_to_cell(9.0, 5.0), (21, 12));
+    assert_eq!(viewport.cell_to_screen(21, 12), (8.0, 4.0));
+
+    // zooming keeps the cell under the pointer where it was
+    viewport.zoom_at(100.0, 60.0, 2.0);
+    assert_eq!(viewport.scale(), 8.0);
+    assert_eq!(viewport.screen_to_cell(100.0, 60.0), (35, 35));
+
+    viewport.pan(16.0, -8.0);
+    assert_eq!(viewport.screen_to_cell(116.0, 52.0), (35, 35));
+}
+
+#[test]
+pub fn test_dirty_rects() {
+    // a blinker in each of two distant corners of a 40x40 grid
+    let mut universe = Universe::new(40, 40);
+    universe.set_cells(&[(2, 1), (2, 2), (2, 3), (37, 35), (37, 36), (37, 37)]);
+    universe.tick(1);
+
+    let rects = universe.dirty_rects();
+    assert_eq!(rects.len(), 2);
+    assert_eq!((rects[0].row, rects[0].col, rects[0].width, rects[0].height), (0, 0, 16, 16));
+    // the last tiles are clipped to the grid
+    assert_eq!((rects[1].row, rects[1].col, rects[1].width, rects[1].height), (32, 32, 8, 8));
+
+    universe.die();
+    universe.tick(1);
+    assert!(universe.dirty_rects().is_empty());
+}
+
+#[test]
+pub fn test_stable_front_buffer() {
+    let mut universe = input_spaceship();
+    let ptr = universe.get_cells().as_ptr();
+    universe.tick(1);
+    assert_eq!(universe.get_cells(), expected_spaceship().get_cells());
+    universe.advance(23);
+    assert_eq!(universe.get_cells(), input_spaceship().get_cells());
+    assert_eq!(universe.get_cells().as_ptr(), ptr);
+    assert_eq!(universe.buffer_generation(), 0);
+
+    universe.crop_to_pattern(0);
+    assert_eq!(universe.buffer_generation(), 1);
+}
+
+#[test]
+pub fn test_errors() {
+    let mut universe = Universe::new(4, 4);
+    assert_eq!(universe.set_cells_flat(&[1, 2, 3], Cell::Alive), Err(Error::UnpairedCoordinates));
+    assert_eq!(universe.set_rule_table(&[0; 3]), Err(Error::RuleTableSize { expected: 512, got: 3 }));
+    assert_eq!(universe.import_state(&[1, 0]), Err(Error::SnapshotTruncated));
+    // 65536 x 65536 overflows
+    assert_eq!(universe.import_state(&[0, 0, 1, 0, 0, 0, 1, 0]), Err(Error::SnapshotSizeMismatch));
+    assert_eq!(universe.width(), 4);
+
+    let err = universe.add_rule_region(0, 0, 1, 1, "B39/S23").unwrap_err();
+    assert_eq!(err.to_string(), "invalid neighbor count '9' in rule 'B39/S23'");
+}
+
+#[test]
+pub fn test_checked_coordinates() {
+    let mut universe = Universe::new(4, 3);
+    assert_eq!(universe.toggle_cell_checked(1, 4), Err(Error::OutOfBounds { row: 1, col: 4 }));
+    assert!(universe.get_cells().iter().all(|&cell| cell == Cell::Dead));
+
+    universe.set_strictness(Strictness::Clamp);
+    universe.set_cell_checked(7, 9, Cell::Alive).unwrap();
+    assert_eq!(universe.get_cell(2, 3), Some(Cell::Alive));
+
+    // resizing reallocates the buffer to match
+    universe.set_width(10);
+    assert_eq!(universe.get_cells().len(), 30);
+    universe.set_cell_checked(2, 9, Cell::Alive).unwrap();
+    assert_eq!(universe.get_cell(2, 9), Some(Cell::Alive));
+}
+
+#[test]
+pub fn test_from_cells() {
+    let universe = Universe::from_cells(3, 2, &[0, 1, 0, 0, 7, 1]).unwrap();
+    assert_eq!(universe.get_cell(0, 1), Some(Cell::Alive));
+    assert_eq!(universe.get_cell(1, 1), Some(Cell::Alive));
+    assert_eq!(universe.population(), 3);
+    assert_eq!(Universe::from_cells(3, 3, &[0; 6]), Err(Error::CellCountMismatch { expected: 9, got: 6 }));
+}
+
+#[test]
+pub fn test_from_string() {
+    let universe = Universe::from_string(
+        "
+        ! glider
+        .O.
+        ..O
+        OOO
+        ",
+    )
+    .unwrap();
+    assert_eq!((universe.width(), universe.height()), (3, 3));
+    assert_eq!(universe.population(), 5);
+    assert_eq!(universe.get_cell(0, 1), Some(Cell::Alive));
+    assert_eq!(universe.get_cell(1, 1), Some(Cell::Dead));
+
+    let padded = Universe::from_string_with("#\n_#_#", '#', '_').unwrap();
+    assert_eq!((padded.width(), padded.height()), (4, 2));
+    assert_eq!(padded.get_cell(0, 0), Some(Cell::Alive));
+    assert_eq!(padded.get_cell(0, 3), Some(Cell::Dead));
+
+    assert_eq!(Universe::from_string("O.\n.x"), Err(Error::UnexpectedChar { c: 'x', row: 1, col: 1 }));
+}
+
+#[test]
+pub fn test_render_braille() {
+    let universe = Universe::from_string(
+        "
+        .O.
+        ..O
+        OOO
+        ",
+    )
+    .unwrap();
+    // left block: dots 3, 4 and 6 (U+282C), right block: dots 2 and 3 (U+2806)
+    assert_eq!(universe.render_braille(), "\u{282C}\u{2806}");
+}
+
+#[test]
+pub fn test_ages_and_rgba() {
+    // a block next to a blinker - the block ages, the blinker's ends keep being reborn
+    let mut universe = Universe::from_string(
+        "
+        OO.....
+        OO.....
+        .......
+        .......
+        ...OOO.
+        .......
+        ",
+    )
+    .unwrap();
+    universe.set_wrap(false, false);
+    universe.set_track_ages(true);
+    universe.tick(2);
+
+    let ages = universe.ages().unwrap();
+    assert_eq!(ages[universe.get_index(0, 0)], 3);
+    assert_eq!(ages[universe.get_index(4, 3)], 1);
+    assert_eq!(ages[universe.get_index(4, 4)], 3);
+    assert_eq!(ages[universe.get_index(3, 4)], 0);
+
+    universe
+        .set_palette(Palette {
+            dead: 0x000000FF,
+            ages: vec![0xFF0000FF, 0x00FF00FF],
+            ..Palette::default()
+        })
+        .unwrap();
+    let rgba = universe.render_rgba_aged();
+    assert_eq!(rgba.len(), 7 * 6 * 4);
+    assert_eq!(&rgba[0..4], &[0x00, 0xFF, 0x00, 0xFF]); // old block cell
+    assert_eq!(&rgba[8..12], &[0x00, 0x00, 0x00, 0xFF]); // dead
+    assert_eq!(universe.set_palette(Palette { dead: 0, ages: vec![], ..Palette::default() }), Err(Error::EmptyPalette));
+}
+
+#[test]
+pub fn test_sound_data() {
+    // a vertical blinker turns horizontal: dies at the top and bottom of column 2 and is
+    // born at columns 1 and 3, on either side of the band boundary
+    let mut universe = Universe::from_string(
+        "
+        ......
+        ..O...
+        ..O...
+        ..O...
+        ......
+        ",
+    )
+    .unwrap();
+    universe.set_wrap(false, false);
+    universe.set_sonification(2, Axis::Columns);
+    universe.tick(1);
+
+    // each band is 3 columns x 5 rows
+    let sound = universe.sound_data();
+    assert_eq!(sound, &[1.0 / 15.0, 1.0 / 15.0, 2.0 / 15.0, 0.0]);
+}
+
+#[test]
+pub fn test_tick_partial() {
+    let mut universe = input_spaceship();
+    assert!(!universe.tick_partial(4));
+    assert!(universe.tick_in_progress());
+    // nothing changes until the last row is done
+    assert_eq!(universe.get_cells(), input_spaceship().get_cells());
+    assert!(universe.tick_partial(4));
+    assert!(!universe.tick_in_progress());
+    assert_eq!(universe.get_cells(), expected_spaceship().get_cells());
+}
+
+#[test]
+pub fn test_full_snapshot() {
+    let mut universe = input_spaceship();
+    universe.set_wrap(true, false);
+    universe.set_track_ages(true);
+    universe.add_rule_region(0, 0, 2, 2, "B36/S23").unwrap();
+    universe.tick(3);
+
+    let bytes = universe.take_snapshot_bytes();
+    let restored = Universe::from_snapshot_bytes(&bytes).unwrap();
+    assert_eq!(restored.generation(), 3);
+    assert_eq!(restored.get_cells(), universe.get_cells());
+    assert_eq!(restored.ages(), universe.ages());
+    assert_eq!((restored.wrap_x(), restored.wrap_y()), (true, false));
+
+    let mut continued = universe.clone();
+    continued.tick(5);
+    let mut restored = restored;
+    restored.tick(5);
+    assert_eq!(restored.get_cells(), continued.get_cells());
+
+    assert_eq!(Universe::from_snapshot_bytes(&bytes[..bytes.len() - 1]), Err(Error::SnapshotTruncated));
+    assert_eq!(Universe::from_snapshot_bytes(&universe.export_state()), Err(Error::UnknownSnapshotFormat));
+
+    // a 65536 x 65536 header overflows
+    let mut bytes = bytes;
+    bytes[4..12].copy_from_slice(&[0, 0, 1, 0, 0, 0, 1, 0]);
+    assert_eq!(Universe::from_snapshot_bytes(&bytes), Err(Error::SnapshotSizeMismatch));
+    assert!(matches!(Universe::from_cells(65536, 65536, &[]), Err(Error::CellCountMismatch { got: 0, .. })));
+}
+
+#[test]
+pub fn test_full_snapshot_histories() {
+    let mut universe = Universe::from_string(".....\n.....\n.OOO.\n.....\n.....").unwrap();
+    universe.set_life_history(true);
+    universe.set_marked(0, 0, true).unwrap();
+    universe.set_track_exposure(true);
+    universe.tick(3);
+
+    let bytes = universe.take_snapshot_bytes();
+    let restored = Universe::from_snapshot_bytes(&bytes).unwrap();
+    assert_eq!(restored.life_history_states(), universe.life_history_states());
+    assert_eq!(restored.exposure(), universe.exposure());
+    assert_eq!(restored.exposed_generations(), 3);
+    assert_eq!(Universe::from_snapshot_bytes(&bytes[..bytes.len() - 1]), Err(Error::SnapshotTruncated));
+
+    // version 1 had one flag byte (the low one, at 20) and no histories
+    let mut universe = Universe::from_string(".....\n.....\n.OOO.\n.....\n.....").unwrap();
+    universe.set_wrap(true, false);
+    let mut bytes = universe.take_snapshot_bytes();
+    bytes[3] = 1;
+    bytes.remove(21);
+    let restored = Universe::from_snapshot_bytes(&bytes).unwrap();
+    assert!(restored.same_state(&universe));
+}
+
+#[test]
+pub fn test_xorshift() {
+    use game_of_life_core::random::{Rng, Xorshift};
+
+    // same seed, same soup
+    let mut a = Universe::new(16, 16);
+    let mut b = Universe::new(16, 16);
+    a.reseed(7);
+    b.reseed(7);
+    a.randomize();
+    b.randomize();
+    assert_eq!(a.get_cells(), b.get_cells());
+    assert!(a.density() > 0.3 && a.density() < 0.7);
+
+    let mut rng = Xorshift::new(0);
+    assert!((0..100).all(|_| !rng.gen_bool(0.0) && rng.gen_bool(1.0)));
+    assert!((0..100).map(|_| rng.gen_f32()).all(|x| (0.0..1.0).contains(&x)));
+}
+
+#[test]
+pub fn test_canonical_hash() {
+    let glider = Universe::from_string(".O.\n..O\nOOO").unwrap();
+    // the same glider mirrored, rotated and shifted
+    let mirrored = Universe::from_string("......\n....O.\n...O..\n...OOO").unwrap();
+    let rotated = Universe::from_string("O..\nO.O\nOO.").unwrap();
+    assert_eq!(glider.canonical_hash(), mirrored.canonical_hash());
+    assert_eq!(glider.canonical_hash(), rotated.canonical_hash());
+
+    // a glider 4 generations later is the same object moved
+    let mut later = input_spaceship();
+    later.tick(4);
+    assert_eq!(later.canonical_hash(), glider.canonical_hash());
+
+    let blinker = Universe::from_string("OOO").unwrap();
+    assert_ne!(blinker.canonical_hash(), glider.canonical_hash());
+    assert_eq!(Pattern::from_universe(&blinker).orientations().len(), 2);
+}
+
+#[test]
+pub fn test_explore_rules() {
+    use game_of_life_core::explorer::explore_rules;
+    use game_of_life_core::random::Xorshift;
+
+    let scores = explore_rules(12, 24, 24, 20, &mut Xorshift::new(3));
+    assert_eq!(scores.len(), 12);
+    assert!(scores.windows(2).all(|pair| pair[0].score >= pair[1].score));
+    for score in &scores {
+        assert!(score.rule.starts_with('B') && score.rule.contains("/S"));
+        assert!((0.0..=1.0).contains(&score.activity) && (0.0..=1.0).contains(&score.entropy));
+    }
+}
+
+#[test]
+pub fn test_edit_symmetry() {
+    let mut universe = Universe::new(8, 6);
+    universe.set_edit_symmetry(Symmetry::FourFold);
+    universe.toggle_cell(1, 2);
+    assert_eq!(universe.population(), 4);
+    assert_eq!(universe.get_cell(4, 5), Some(Cell::Alive));
+
+    universe.die();
+    universe.set_edit_symmetry(Symmetry::Horizontal);
+    universe.draw_line(0, 0, 2, 2, Cell::Alive);
+    assert_eq!(universe.population(), 6);
+    assert_eq!(universe.get_cell(2, 5), Some(Cell::Alive));
+
+    universe.die();
+    universe.set_edit_symmetry(Symmetry::None);
+    universe.set_wrap(false, true);
+    universe.stamp(&Pattern::from_string(".O.\n..O\nOOO").unwrap(), 3, 6);
+    // clipped at the right edge, which doesn't wrap
+    assert_eq!(universe.population(), 3);
+}
+
+#[test]
+pub fn test_layers() {
+    use game_of_life_core::layers::{LayerId, LayeredUniverse, Overlap};
+
+    // a block on each layer, overlapping in one corner
+    let mut layers = LayeredUniverse::new(8, 8);
+    layers.layer_mut(LayerId::A).set_cells(&[(1, 1), (1, 2), (2, 1), (2, 2)]);
+    layers.layer_mut(LayerId::B).set_cells(&[(2, 2), (2, 3), (3, 2), (3, 3)]);
+    layers.set_overlap(LayerId::B, Overlap::Die);
+    layers.tick();
+
+    // under its own rule B would have kept (2, 2), but A covers it
+    assert_eq!(layers.layer(LayerId::A).population(), 4);
+    assert_eq!(layers.layer(LayerId::B).get_cell(2, 2), Some(Cell::Dead));
+    assert_eq!(layers.layer(LayerId::B).get_cell(3, 3), Some(Cell::Alive));
+}
+
+#[test]
+pub fn test_walls() {
+    let mut universe = Universe::from_string("..#..\n.O.O.\n.....\n.....").unwrap();
+    universe.set_wrap(false, false);
+    universe.set_track_ages(true);
+    assert_eq!(universe.wall_count(), 1);
+
+    // two live neighbors and a dead wall aren't enough for a birth
+    assert!(universe.verify_against_reference(3).is_ok());
+    universe.tick(1);
+    assert_eq!(universe.get_cell(1, 2), Some(Cell::Dead));
+    assert_eq!(universe.get_cell(0, 2), Some(Cell::Wall));
+    assert_eq!(universe.ages().unwrap()[2], 0);
+
+    let mut universe = Universe::from_string("..#..\n.O.O.\n.....\n.....").unwrap();
+    universe.set_wrap(false, false);
+    universe.set_walls_alive(true);
+    assert!(universe.verify_against_reference(3).is_ok());
+    universe.tick(1);
+    assert_eq!(universe.get_cell(1, 2), Some(Cell::Alive));
+
+    // walls survive clearing and fills, and round trip through snapshots
+    universe.fill(FillPreset::Checkerboard);
+    assert_eq!(universe.get_cell(0, 2), Some(Cell::Wall));
+    universe.die();
+    let restored = Universe::from_snapshot_bytes(&universe.take_snapshot_bytes()).unwrap();
+    assert_eq!(restored.get_cells(), universe.get_cells());
+    assert!(restored.walls_alive());
+    universe.clear_walls();
+    assert_eq!(universe.get_cell(0, 2), Some(Cell::Dead));
+}
+
+#[test]
+pub fn test_b0_rules() {
+    use game_of_life_core::rules::parse_rule;
+
+    // without emulation an empty B0 universe would fill up on the first tick
+    for rule in ["B0/S8", "B013/S12", "B02/S"] {
+        let mut universe = Universe::new(12, 12);
+        universe.set_rule_table(&parse_rule(rule).unwrap()).unwrap();
+        universe.tick(3);
+        assert_eq!(universe.population(), 0, "{}", rule);
+
+        universe.set_cells(&[(4, 4), (4, 5), (5, 6), (6, 4)]);
+        assert!(universe.verify_against_reference(8).is_ok(), "{}", rule);
+    }
+
+    // under B0/S a lone cell keeps its 3x3 neighborhood from being born while the rest of the
+    // plane fills up, which shows as a 3x3 block against the background
+    let mut universe = Universe::new(8, 8);
+    universe.set_wrap(false, false);
+    universe.set_rule_table(&parse_rule("B0/S").unwrap()).unwrap();
+    universe.set_cells(&[(3, 3)]);
+    universe.tick(1);
+    assert_eq!(universe.population(), 9);
+    assert_eq!(universe.get_cell(2, 2), Some(Cell::Alive));
+}
+
+#[test]
+pub fn test_find_pattern() {
+    let glider = Pattern::from_string(".O.\n..O\nOOO").unwrap();
+    let mut universe = Universe::new(12, 10);
+    universe.stamp(&glider, 1, 1);
+    // the same glider mirrored (".O.", "O..", "OOO"), wrapping around the right edge
+    universe.set_cells(&[(5, 11), (6, 10), (7, 10), (7, 11), (7, 0)]);
+
+    assert_eq!(universe.find_pattern(&glider, false), vec![(1, 1)]);
+    assert_eq!(universe.find_pattern(&glider, true), vec![(1, 1), (5, 10)]);
+
+    // a live cell inside the box spoils the match
+    universe.set_cell(1, 1, Cell::Alive);
+    assert_eq!(universe.find_pattern(&glider, true), vec![(5, 10)]);
+}
+
+#[test]
+pub fn test_apgcode() {
+    let conway = game_of_life_core::rules::conway_table();
+    let apgcode = |text: &str| Pattern::from_string(text).unwrap().apgcode(&conway, 8);
+
+    assert_eq!(apgcode("OO\nOO").as_deref(), Some("xs4_33"));
+    assert_eq!(apgcode(".OO.\nO..O\n.OO.").as_deref(), Some("xs6_696"));
+    assert_eq!(apgcode("OOO").as_deref(), Some("xp2_7"));
+    assert_eq!(apgcode("O..\n.OO\nOO.").as_deref(), Some("xq4_153"));
+    assert_eq!(apgcode(".O..O\nO....\nO...O\nOOOO.").as_deref(), Some("xq4_6frc"));
+    // the R-pentomino takes over a thousand generations to settle
+    assert_eq!(apgcode(".OO\nOO.\n.O."), None);
+
+    // blank columns inside a strip are run-length coded
+    assert_eq!(Pattern::new(&[(0, 0), (0, 3), (0, 9)]).extended_wechsler(), "1w1y11");
+}
+
+#[test]
+pub fn test_rle() {
+    let glider = Pattern::from_rle("#N Glider\nx = 3, y = 3, rule = B3/S23\nbo$2bo$3o!").unwrap();
+    assert_eq!(glider, Pattern::from_string(".O.\n..O\nOOO").unwrap());
+    // blank rows and runs split over lines
+    let spaced = Pattern::from_rle("o2$\n3o!").unwrap();
+    assert_eq!((spaced.height(), spaced.population()), (3, 4));
+    assert!(matches!(Pattern::from_rle("2o*!"), Err(Error::UnexpectedChar { c: '*', .. })));
+    // runs are checked before they're expanded
+    assert_eq!(Pattern::from_rle("4000000000o!"), Err(Error::OutOfBounds { row: 0, col: 3999999999 }));
+    assert_eq!(Pattern::from_rle("x = 3, y = 2\nb3o!"), Err(Error::OutOfBounds { row: 0, col: 3 }));
+}
+
+#[cfg(feature = "lexicon")]
+#[test]
+pub fn test_lexicon() {
+    use game_of_life_core::lexicon;
+
+    // every named entry decodes, and the names are distinct once normalized
+    let mut keys: Vec<String> = lexicon::names()
+        .map(|name| name.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect())
+        .collect();
+    for name in lexicon::names() {
+        assert!(lexicon::lookup(name).is_some_and(|pattern| pattern.population() > 0), "{}", name);
+    }
+    assert!(keys.len() > 100);
+    keys.sort();
+    keys.dedup();
+    assert_eq!(keys.len(), lexicon::names().count());
+
+    // the named objects are the ones the census files under their apgcodes
+    let conway = game_of_life_core::rules::conway_table();
+    for (name, code) in [
+        ("block", "xs4_33"),
+        ("tub", "xs4_252"),
+        ("boat", "xs5_253"),
+        ("beehive", "xs6_696"),
+        ("ship", "xs6_356"),
+        ("loaf", "xs7_2596"),
+        ("pond", "xs8_6996"),
+        ("blinker", "xp2_7"),
+        ("toad", "xp2_7e"),
+        ("beacon", "xp2_318c"),
+    ] {
+        assert_eq!(lexicon::lookup(name).unwrap().apgcode(&conway, 4).as_deref(), Some(code), "{}", name);
+        assert!(lexicon::census_codes().any(|census| census == code), "{}", code);
+    }
+
+    // the census is every strict still life of 4 to 13 cells and three oscillators, each
+    // found under its own apgcode, and none of it is searchable by name
+    for code in lexicon::census_codes() {
+        assert_eq!(lexicon::lookup(code).unwrap().apgcode(&conway, 4).as_deref(), Some(code));
+    }
+    let still_lifes = |cells: u32| lexicon::census_codes().filter(|code| code.starts_with(&format!("xs{}_", cells))).count();
+    assert_eq!((4..=13).map(still_lifes).collect::<Vec<_>>(), vec![2, 1, 5, 4, 9, 10, 25, 46, 121, 240]);
+    assert_eq!(lexicon::census_codes().count(), 466);
+    assert!(lexicon::search("xs6").is_empty());
+
+    assert_eq!(lexicon::lookup("LWSS").unwrap().apgcode(&conway, 4).as_deref(), Some("xq4_6frc"));
+    assert_eq!(lexicon::search("pentomino")[0], "f-pentomino");
+    assert_eq!(lexicon::search("glider")[0], "glider");
+
+    let mut universe = Universe::new(16, 16);
+    universe.insert_named("R-Pentomino", 2, 2).unwrap();
+    assert_eq!(universe.population(), 5);
+    assert_eq!(universe.insert_named("nope", 0, 0), Err(Error::UnknownPattern(String::from("nope"))));
+}
+
+#[test]
+pub fn test_from_image_data() {
+    let rgba = [
+        0, 0, 0, 255, // black
+        255, 255, 255, 255, // white
+        200, 30, 30, 255, // dark red
+        0, 0, 0, 0, // transparent black counts as white
+    ];
+    let universe = Universe::from_image_data(2, 2, &rgba, 128).unwrap();
+    assert_eq!(universe.get_cells(), &[Cell::Alive, Cell::Dead, Cell::Alive, Cell::Dead]);
+
+    assert_eq!(
+        Universe::from_image_data(2, 2, &rgba[..12], 128),
+        Err(Error::ImageSize { expected: 16, got: 12 })
+    );
+}
+
+#[test]
+pub fn test_long_exposure() {
+    // a blinker: the center is always alive, the four arms every other generation
+    let mut universe = Universe::from_string(".....\n.....\n.OOO.\n.....\n.....").unwrap();
+    universe.set_track_exposure(true);
+    universe.tick(4);
+
+    let exposure = universe.exposure().unwrap();
+    assert_eq!(universe.exposed_generations(), 4);
+    assert_eq!(exposure[universe.get_index(2, 2)], 4);
+    assert_eq!(exposure[universe.get_index(1, 2)], 2);
+    assert_eq!(exposure[universe.get_index(2, 1)], 2);
+    assert_eq!(exposure[0], 0);
+
+    universe.render_heatmap();
+    let heatmap = universe.heatmap();
+    assert_eq!(heatmap.len(), 25);
+    assert_eq!(heatmap[universe.get_index(2, 2)], 1.0);
+    assert_eq!(heatmap[universe.get_index(1, 2)], 0.5);
+    assert_eq!(heatmap[0], 0.0);
+
+    let palette = Palette {
+        dead: 0x000000FF,
+        ages: vec![0x0000FFFF, 0x00FF00FF, 0xFF0000FF],
+        ..Palette::default()
+    };
+    let rgba = universe.export_long_exposure_rgba(&palette).unwrap();
+    let pixel = |row, col| &rgba[universe.get_index(row, col) * 4..][..4];
+    assert_eq!(pixel(0, 0), &[0x00, 0x00, 0x00, 0xFF]);
+    assert_eq!(pixel(1, 2), &[0x00, 0x00, 0xFF, 0xFF]);
+    assert_eq!(pixel(2, 2), &[0xFF, 0x00, 0x00, 0xFF]);
+}
+
+#[test]
+pub fn test_population_history() {
+    let mut universe = Universe::from_string(".....\n.....\n.OOO.\n.....\n.....").unwrap();
+    universe.set_population_history(4);
+    universe.tick(3);
+
+    // a blinker: 2 births and 2 deaths every generation
+    let history = universe.population_history().unwrap();
+    assert_eq!(history.stride(), 1);
+    assert_eq!(history.to_f32(), vec![3.0, 2.0, 2.0, 3.0, 2.0, 2.0, 3.0, 2.0, 2.0]);
+
+    // the fourth sample fills it up and pairs get merged
+    universe.tick(1);
+    let history = universe.population_history().unwrap();
+    assert_eq!(history.stride(), 2);
+    assert_eq!(history.to_f32(), vec![3.0, 4.0, 4.0, 3.0, 4.0, 4.0]);
+
+    // tick_partial records too
+    while !universe.tick_partial(2) {}
+    universe.tick(1);
+    assert_eq!(universe.population_history().unwrap().samples().len(), 3);
+
+    // an odd capacity is rounded up, so every merged sample covers the whole stride
+    universe.set_population_history(3);
+    universe.tick(4);
+    let history = universe.population_history().unwrap();
+    assert_eq!(history.capacity(), 4);
+    assert_eq!(history.to_f32(), vec![3.0, 4.0, 4.0, 3.0, 4.0, 4.0]);
+}
+
+#[test]
+pub fn test_max_age() {
+    let mut universe = Universe::from_string("......\n.OO...\n.OO...\n......").unwrap();
+    universe.set_max_age(3);
+    assert!(universe.ages().is_some());
+
+    // the block lives exactly 3 generations
+    universe.tick(2);
+    assert_eq!(universe.population(), 4);
+    assert!(universe.verify_against_reference(4).is_ok());
+    universe.tick(1);
+    assert_eq!(universe.population(), 0);
+
+    universe.set_cells(&[(1, 1), (1, 2), (2, 1), (2, 2)]);
+    let restored = Universe::from_snapshot_bytes(&universe.take_snapshot_bytes()).unwrap();
+    assert_eq!(restored.max_age(), 3);
+}
+
+#[test]
+pub fn test_label_components() {
+    let mut universe = Universe::from_string("OO....O\nOO.....\n.......\n...O...\n....O..\n......O").unwrap();
+    let components = universe.label_components();
+    // on the torus the block joins up with both cells in the right column, one across the
+    // left edge and one diagonally across the corner
+    assert_eq!(components.count(), 2);
+    assert_eq!(components.sizes(), &[6, 2]);
+    assert_eq!(components.labels()[universe.get_index(3, 3)], 2);
+    assert_eq!(components.labels()[universe.get_index(2, 0)], 0);
+
+    universe.set_wrap(false, false);
+    let components = universe.label_components();
+    assert_eq!(components.sizes(), &[4, 1, 2, 1]);
+}
+
+#[test]
+pub fn test_boolean_ops() {
+    let block = Universe::from_string("OO\nOO").unwrap();
+    let mut universe = Universe::from_string("O...\n....\n....").unwrap();
+
+    universe.union(&block, 1, 1);
+    assert_eq!(universe.population(), 5);
+    assert_eq!(universe.diff_cells(&block, 1, 1), vec![(0, 0)]);
+
+    // shifted half off the bottom right corner
+    universe.xor(&block, 2, 2);
+    assert_eq!(universe.population(), 5);
+    assert_eq!(universe.get_cell(2, 2), Some(Cell::Dead));
+    assert_eq!(universe.get_cell(2, 3), Some(Cell::Alive));
+
+    universe.intersect(&block, 1, 1);
+    assert_eq!(universe.population(), 3);
+    assert_eq!(universe.get_cell(0, 0), Some(Cell::Dead));
+}
+
+#[test]
+pub fn test_runner_auto_speed() {
+    let mut universe = Universe::new(16, 16);
+    let mut runner = Runner::new();
+    runner.set_generations_per_frame(4);
+    assert_eq!(runner.frame(&mut universe), 4);
+    assert_eq!(universe.generation(), 4);
+
+    // frames well under the 60 fps budget speed it up
+    runner.set_target_fps(Some(60.0));
+    for _ in 0..10 {
+        runner.report_frame_time(8.0);
+    }
+    let fast = runner.generations_per_frame();
+    assert!(fast > 4);
+
+    // and frames over it slow it down, never below one generation
+    for _ in 0..50 {
+        runner.report_frame_time(40.0);
+    }
+    assert!(runner.generations_per_frame() < fast);
+    assert_eq!(runner.generations_per_frame(), 1);
+
+    // frames of 2 ms plus 0.5 ms a generation settle where they fill the 60 fps budget
+    let mut run = |runner: &mut Runner, per_generation: f64| {
+        let generations = runner.frame(&mut universe);
+        runner.report_frame_time(2.0 + per_generation * generations as f64);
+        runner.generations_per_frame()
+    };
+    let rates: Vec<u32> = (0..40).map(|_| run(&mut runner, 0.5)).collect();
+    assert!((28..=30).contains(rates.last().unwrap()), "{:?}", rates);
+
+    // twice as expensive slows it down to the new fill without overshooting below it
+    let rates: Vec<u32> = (0..40).map(|_| run(&mut runner, 1.0)).collect();
+    assert!(rates.iter().all(|&rate| rate >= 14), "{:?}", rates);
+    assert!((14..=15).contains(rates.last().unwrap()), "{:?}", rates);
+}
+
+#[test]
+pub fn test_preview() {
+    let mut universe = Universe::new(6, 6);
+    universe.set_wrap(false, false);
+    let glider = Pattern::from_string(".O.\n..O\nOOO").unwrap();
+    universe.set_preview(&glider, 4, 0, Transform::Rotate90);
+    // rotated the bottom row becomes the left column, and the rest falls off the bottom edge
+    assert_eq!(universe.preview_cells(), vec![universe.get_index(4, 0), universe.get_index(5, 0), universe.get_index(5, 2)]);
+    assert_eq!(universe.population(), 0);
+
+    let rgba = universe.render_rgba_aged().to_vec();
+    let pixel = |idx: usize| &rgba[idx * 4..][..4];
+    assert_eq!(pixel(universe.get_index(4, 0)), &[0x8E, 0xC7, 0xFF, 0xFF]);
+    assert_eq!(pixel(universe.get_index(4, 1)), &[0xFF, 0xFF, 0xFF, 0xFF]);
+
+    assert!(universe.commit_preview());
+    assert!(!universe.has_preview());
+    assert_eq!(universe.population(), 3);
+    assert_eq!(universe.get_cell(5, 2), Some(Cell::Alive));
+    assert!(!universe.commit_preview());
+
+    // on a torus the glider straddling the bottom right corner wraps, ghost and stamp alike
+    let mut universe = Universe::new(6, 6);
+    universe.set_preview(&glider, 4, 4, Transform::Identity);
+    let wrapped = vec![
+        universe.get_index(0, 0),
+        universe.get_index(0, 4),
+        universe.get_index(0, 5),
+        universe.get_index(4, 5),
+        universe.get_index(5, 0),
+    ];
+    assert_eq!(universe.preview_cells(), wrapped);
+    universe.commit_preview();
+    let alive: Vec<usize> = (0..36).filter(|&idx| universe.get_cells()[idx] == Cell::Alive).collect();
+    assert_eq!(alive, wrapped);
+}
+
+#[test]
+pub fn test_hook_events() {
+    let mut universe = Universe::from_string(".....\n.OOO.\n.....").unwrap();
+    universe.set_wrap(false, false);
+    universe.set_hook_interval(3);
+
+    // one call stepping past several multiples still records each of them
+    universe.advance(7);
+    let events = universe.take_hook_events();
+    assert_eq!(events.iter().map(|stats| stats.generation).collect::<Vec<_>>(), vec![3, 6]);
+    assert_eq!((events[0].population, events[0].births, events[0].deaths), (3, 2, 2));
+    assert!(universe.take_hook_events().is_empty());
+
+    universe.tick(1);
+    while !universe.tick_partial(1) {}
+    assert_eq!(universe.take_hook_events()[0].generation, 9);
+
+    universe.set_hook_interval(0);
+    universe.advance(10);
+    assert!(universe.take_hook_events().is_empty());
+}
+
+#[test]
+pub fn test_injector() {
+    let mut universe = Universe::new(20, 20);
+    universe.set_wrap(false, false);
+    universe.set_injector(Some(Injector::new(5, Injection::Glider)));
+
+    universe.advance(4);
+    assert_eq!(universe.population(), 0);
+    universe.tick(1);
+    assert_eq!(universe.population(), 5);
+    let glider = Pattern::from_string(".O.\n..O\nOOO").unwrap();
+    assert_eq!(universe.find_pattern(&glider, true).len(), 1);
+
+    // a soup never lands on or next to anything alive
+    let mut injector = Injector::new(1, Injection::Soup);
+    injector.set_soup(3, 1.0);
+    let mut universe = Universe::from_string("...\n.O.\n...").unwrap();
+    universe.set_injector(Some(injector));
+    assert!(!universe.inject());
+    assert_eq!(universe.population(), 1);
+
+    // on a twisted torus a solid soup crossing the seam is still one block to the rules, with
+    // exactly one cell that has eight neighbors
+    for seed in 0..20 {
+        let mut injector = Injector::new(1, Injection::Soup);
+        injector.set_soup(3, 1.0);
+        let mut universe = Universe::new(5, 5);
+        universe.set_twist(1, 0).unwrap();
+        universe.reseed(seed);
+        universe.set_injector(Some(injector));
+        assert!(universe.inject());
+        let centers = (0..25).filter(|&idx| universe.live_neighbor_count(idx / 5, idx % 5) == 8).count();
+        assert_eq!(centers, 1, "seed {}", seed);
+    }
+}
+
+#[test]
+pub fn test_twisted_torus() {
+    let mut universe = Universe::new(5, 5);
+    universe.set_cells(&[(4, 2)]);
+    assert_eq!(universe.live_neighbor_count(0, 0), 0);
+
+    // going up from the top row lands two columns to the left
+    universe.set_twist(0, 2).unwrap();
+    assert_eq!(universe.live_neighbor_count(0, 0), 1);
+    assert_eq!(universe.set_twist(1, 2), Err(Error::DoubleTwist));
+
+    // stamps and their preview cross the seam the same way, down past the bottom lands two
+    // columns to the right
+    let domino = Pattern::from_string("O\nO").unwrap();
+    universe.set_preview(&domino, 4, 1, Transform::Identity);
+    assert_eq!(universe.preview_cells(), vec![universe.get_index(0, 3), universe.get_index(4, 1)]);
+    universe.commit_preview();
+    assert_eq!(universe.get_cell(0, 3), Some(Cell::Alive));
+    assert_eq!(universe.get_cell(0, 1), Some(Cell::Dead));
+
+    let mut universe = Universe::new(12, 9);
+    universe.reseed(3);
+    universe.randomize();
+    for twist in [(0, 5), (4, 0), (0, -7)] {
+        universe.set_twist(twist.0, twist.1).unwrap();
+        assert_eq!(universe.verify_against_reference(20), Ok(()));
+    }
+    let restored = Universe::from_snapshot_bytes(&universe.take_snapshot_bytes()).unwrap();
+    assert_eq!(restored.twist(), (0, -7));
+}
+
+#[test]
+pub fn test_reseed_replays() {
+    let run = |seed| {
+        let mut universe = Universe::new(24, 24);
+        universe.reseed(seed);
+        universe.randomize_symmetric(0.3, Symmetry::None);
+        universe.set_injector(Some(Injector::new(3, Injection::Soup)));
+        universe.advance(30);
+        universe
+    };
+    assert_eq!(run(11).get_cells(), run(11).get_cells());
+    assert_ne!(run(11).get_cells(), run(12).get_cells());
+
+    let mut universe = Universe::new(16, 16);
+    universe.reseed(5);
+    universe.randomize();
+    let first = universe.get_cells().to_vec();
+    universe.randomize();
+    assert_ne!(universe.get_cells(), &first[..]);
+    universe.reseed(5);
+    universe.randomize();
+    assert_eq!(universe.get_cells(), &first[..]);
+}
+
+#[test]
+pub fn test_tick_until_population() {
+    // a domino dies out in one generation
+    let mut universe = Universe::from_string("....\n.OO.\n....").unwrap();
+    assert_eq!(universe.tick_until_population_below(1, 10), Some(1));
+    assert_eq!(universe.tick_until_population_below(1, 10), Some(0));
+    assert_eq!(universe.generation(), 1);
+
+    // a blinker never drops below 3, so the limit comes first
+    let mut universe = Universe::from_string(".....\n.OOO.\n.....\n.....").unwrap();
+    assert_eq!(universe.tick_until_population_below(3, 25), None);
+    assert_eq!(universe.generation(), 25);
+
+    // the R-pentomino grows past 6 cells in a few generations
+    let mut universe = Universe::from_string("......\n..OO..\n.OO...\n..O...\n......\n......").unwrap();
+    assert_eq!(universe.tick_until_population_above(5, 0), None);
+    assert!(universe.tick_until_population_above(6, 10).unwrap() > 0);
+    assert!(universe.population() > 6);
+}
+
+#[test]
+pub fn test_runner_generation_limit() {
+    let mut universe = Universe::from_string(".....\n.OOO.\n.....\n.....").unwrap();
+    let mut runner = Runner::new();
+    runner.set_generations_per_frame(4);
+    runner.set_generation_limit(Some(10));
+
+    assert_eq!(runner.frame(&mut universe), 4);
+    assert_eq!(runner.frame(&mut universe), 4);
+    assert!(runner.completed().is_none());
+    // the last frame runs short to stop on the limit
+    assert_eq!(runner.frame(&mut universe), 2);
+    assert_eq!(universe.generation(), 10);
+    let stats = runner.completed().unwrap();
+    assert_eq!((stats.generation, stats.population, stats.births, stats.deaths), (10, 3, 2, 2));
+
+    assert_eq!(runner.frame(&mut universe), 0);
+    assert_eq!(universe.generation(), 10);
+    runner.set_generation_limit(None);
+    assert_eq!(runner.frame(&mut universe), 4);
+}
+
+#[test]
+pub fn test_analyze_growth() {
+    use game_of_life_core::growth::Growth;
+    use game_of_life_core::rules::parse_rule;
+
+    let mut blinker = Universe::new(5, 5);
+    blinker.set_cells(&[(2, 1), (2, 2), (2, 3)]);
+    let growth = blinker.analyze_growth(200, 50);
+    assert_eq!(growth.class, Growth::Bounded);
+    assert!((growth.population[0] - 3.0).abs() < 1e-9);
+    assert_eq!(growth.population[1], 0.0);
+
+    // the Gosper glider gun adds a five cell glider every 30 generations
+    let gun = Universe::from_string(
+        "........................O...........\n\
+         ......................O.O...........\n\
+         ............OO......OO............OO\n\
+         ...........O...O....OO............OO\n\
+         OO........O.....O...OO..............\n\
+         OO........O...O.OO....O.O...........\n\
+         ..........O.....O.......O...........\n\
+         ...........O...O....................\n\
+         ............OO......................",
+    )
+    .unwrap();
+    let growth = gun.analyze_growth(900, 90);
+    assert_eq!(growth.class, Growth::Linear);
+    assert!((growth.population[1] - 5.0 / 30.0).abs() < 0.01, "{:?}", growth);
+    assert_eq!(growth.population[2], 0.0);
+
+    // every cell next to a live one is born, a single cell becomes a (2t + 1)^2 square
+    let mut square = Universe::new(3, 3);
+    square.set_rule_table(&parse_rule("B12345678/S012345678").unwrap()).unwrap();
+    square.set_cells(&[(1, 1)]);
+    let growth = square.analyze_growth(100, 20);
+    assert_eq!(growth.class, Growth::Quadratic);
+    for (fitted, exact) in growth.population.iter().zip([1.0, 4.0, 4.0]) {
+        assert!((fitted - exact).abs() < 1e-6, "{:?}", growth);
+    }
+    assert_eq!(growth.population, growth.area);
+}
+
+#[test]
+pub fn test_life_history() {
+    // a blinker leaves a plus sign behind
+    let mut universe = Universe::new(5, 5);
+    universe.set_cells(&[(2, 1), (2, 2), (2, 3)]);
+    universe.set_life_history(true);
+    universe.set_marked(0, 0, true).unwrap();
+    universe.tick(1);
+    let states = universe.life_history_states().unwrap();
+    assert_eq!(&states[5..15], &[0, 0, 1, 0, 0, 0, 2, 1, 2, 0]);
+    assert_eq!(states[0], 4);
+
+    let rle = universe.to_life_history_rle();
+    assert_eq!(rle, "x = 5, y = 5, rule = LifeHistory\nD$2.A$.BAB$2.A!\n");
+    let loaded = Universe::from_life_history_rle(&rle).unwrap();
+    assert_eq!(loaded.life_history_states(), universe.life_history_states());
+    assert_eq!(loaded.get_cells(), universe.get_cells());
+
+    // envelope and marks get their own colors
+    let palette = Palette::default();
+    universe.render_rgba_aged();
+    assert_eq!(universe.rgba()[..4], palette.marked_dead.to_be_bytes());
+    assert_eq!(universe.rgba()[11 * 4..][..4], palette.envelope.to_be_bytes());
+
+    universe.clear_envelope();
+    assert_eq!(universe.life_history_states().unwrap()[11], 0);
+    universe.set_life_history(false);
+    assert_eq!(universe.life_history_states(), None);
+    assert_eq!(Universe::from_life_history_rle("x = 2, y = 1\nAG!"), Err(Error::UnexpectedChar { c: 'G', row: 0, col: 1 }));
+    assert_eq!(
+        Universe::from_life_history_rle("x = 100000, y = 100000\nA!"),
+        Err(Error::TooLarge { width: 100000, height: 100000 })
+    );
+}
+
+#[test]
+pub fn test_export_region() {
+    use game_of_life_core::region::ExportFormat;
+
+    // a glider and a block, only the glider is selected
+    let mut universe = Universe::from_string(
+        ".O......\n\
+         ..O...OO\n\
+         OOO...OO",
+    )
+    .unwrap();
+    let rle = universe.export_region(2, 2, 0, 0, ExportFormat::Rle).unwrap();
+    assert_eq!(String::from_utf8(rle).unwrap(), "x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!\n");
+    let text = universe.export_region(0, 0, 2, 2, ExportFormat::Plaintext).unwrap();
+    assert_eq!(String::from_utf8(text).unwrap(), ".O\n..O\nOOO\n");
+
+    let bytes = universe.export_region(1, 6, 2, 7, ExportFormat::Bytes).unwrap();
+    let mut block = Universe::new(1, 1);
+    block.import_state(&bytes).unwrap();
+    assert_eq!((block.width(), block.height(), block.population()), (2, 2, 4));
+
+    assert_eq!(universe.export_region(0, 0, 3, 8, ExportFormat::Rle), Err(Error::OutOfBounds { row: 3, col: 8 }));
+    universe.set_strictness(Strictness::Clamp);
+    assert_eq!(universe.extract_region(0, 0, 3, 8).unwrap().population(), 9);
+}
+
+#[test]
+pub fn test_backends_agree() {
+    use game_of_life_core::conformance::{Backend, Disagreement};
+    use game_of_life_core::rules::parse_rule;
+
+    let mut universe = Universe::new(11, 7);
+    universe.reseed(7);
+    universe.randomize();
+    universe.set_cell(3, 0, Cell::Wall);
+    universe.add_rule_region(1, 1, 4, 5, "B36/S23").unwrap();
+
+    for &(x, y) in [(true, true), (true, false), (false, true), (false, false)].iter() {
+        universe.set_wrap(x, y);
+        assert_eq!(universe.assert_backends_agree(12), Ok(()));
+    }
+    universe.set_wrap(true, true);
+    universe.set_twist(2, 0).unwrap();
+    assert_eq!(universe.assert_backends_agree(12), Ok(()));
+
+    // B0 backgrounds past a non-wrapping edge
+    universe.set_twist(0, 0).unwrap();
+    universe.set_wrap(false, true);
+    universe.set_rule_table(&parse_rule("B0123/S1").unwrap()).unwrap();
+    assert_eq!(universe.assert_backends_agree(6), Ok(()));
+
+    // a generation tick_partial has half computed only finishes the way tick would if the
+    // cells weren't edited since - here the rows with the blinker are done before it's erased
+    let mut blinker = Universe::new(5, 5);
+    blinker.set_cells(&[(1, 1), (1, 2), (1, 3)]);
+    blinker.tick_partial(3);
+    blinker.die();
+    let disagreement = blinker.assert_backends_agree(1).unwrap_err();
+    assert_eq!(
+        disagreement,
+        Disagreement {
+            backend: Backend::TickPartial,
+            generation: 1,
+            row: 0,
+            col: 2,
+        }
+    );
+    assert_eq!(disagreement.to_string(), "TickPartial disagreed with Tick at generation 1, cell (0, 2)");
+}
+
+#[test]
+pub fn test_same_state_ignores_parity() {
+    use game_of_life_core::rules::parse_rule;
+
+    // a vertical blinker one generation in vs. a horizontal one drawn from scratch
+    let mut ticked = Universe::new(5, 5);
+    ticked.set_cells(&[(1, 2), (2, 2), (3, 2)]);
+    ticked.tick(1);
+    let mut drawn = Universe::new(5, 5);
+    drawn.set_cells(&[(2, 1), (2, 2), (2, 3)]);
+    assert!(ticked.same_state(&drawn));
+
+    // under B0 the parity decides the next generation, so it has to match
+    drawn.set_rule_table(&parse_rule("B0/S8").unwrap()).unwrap();
+    let mut later = drawn.clone();
+    later.tick(1);
+    later.die();
+    later.set_cells(&[(2, 1), (2, 2), (2, 3)]);
+    assert_eq!(later.get_cells(), drawn.get_cells());
+    assert!(!later.same_state(&drawn));
+}
@@ -0,0 +1,50 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+#[wasm_bindgen]
+impl Universe {
+    // keep count of how many generations each cell has been alive, costs an extra pass per tick
+    pub fn set_track_ages(&mut self, track: bool) {
+        self.inner.set_track_ages(track);
+        self.check_memory();
+    }
+
+    // cells alive for more than max_age generations in a row die regardless of their neighbors,
+    // 0 for no limit - turns age tracking on
+    pub fn set_max_age(&mut self, max_age: u16) {
+        self.inner.set_max_age(max_age);
+        self.check_memory();
+    }
+
+    pub fn max_age(&self) -> u16 {
+        self.inner.max_age()
+    }
+
+    // read-only pointer to one u16 age per cell (view it as a Uint16Array), null when ages
+    // aren't tracked
+    pub fn ages(&self) -> *const u16 {
+        self.inner.ages().map_or(std::ptr::null(), |ages| ages.as_ptr())
+    }
+
+    // colors packed 0xRRGGBBAA: one for dead cells, then one per age starting at 1 generation,
+    // the last also used for anything older
+    pub fn set_palette(&mut self, dead: u32, ages: Vec<u32>) -> Result<(), JsError> {
+        let palette = engine::age::Palette {
+            dead,
+            ages,
+            ..self.inner.palette().clone()
+        };
+        Ok(self.inner.set_palette(palette)?)
+    }
+
+    // paint the universe into an RGBA buffer colored by age and return a pointer to it,
+    // 4 bytes per cell - wrap it in an ImageData and putImageData it straight onto a canvas
+    pub fn render_rgba_aged(&mut self) -> *const u8 {
+        let ptr = self.inner.render_rgba_aged().as_ptr();
+        // the buffer is allocated on the first render, which can grow memory
+        self.check_memory();
+        ptr
+    }
+}
@@ -0,0 +1,186 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+// result of Universe::measure_velocity, see engine::analysis::Velocity
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Velocity {
+    pub period: u32,
+    pub dx: i32,
+    pub dy: i32,
+}
+
+impl From<engine::analysis::Velocity> for Velocity {
+    fn from(velocity: engine::analysis::Velocity) -> Velocity {
+        Velocity {
+            period: velocity.period,
+            dx: velocity.dx,
+            dy: velocity.dy,
+        }
+    }
+}
+
+impl From<Velocity> for engine::analysis::Velocity {
+    fn from(velocity: Velocity) -> engine::analysis::Velocity {
+        engine::analysis::Velocity {
+            period: velocity.period,
+            dx: velocity.dx,
+            dy: velocity.dy,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Velocity {
+    pub fn is_spaceship(&self) -> bool {
+        engine::analysis::Velocity::from(*self).is_spaceship()
+    }
+
+    // e.g. "c/4 diagonal", "c/2 orthogonal", "oscillator p2"
+    pub fn label(&self) -> String {
+        engine::analysis::Velocity::from(*self).label()
+    }
+}
+
+// result of Universe::measure_lifespan, see engine::lifespan::Lifespan
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lifespan {
+    pub generations: u64,
+    pub period: u32,
+    pub population: usize,
+}
+
+impl From<engine::lifespan::Lifespan> for Lifespan {
+    fn from(lifespan: engine::lifespan::Lifespan) -> Lifespan {
+        Lifespan {
+            generations: lifespan.generations,
+            period: lifespan.period,
+            population: lifespan.population,
+        }
+    }
+}
+
+// mirrors engine::growth::Growth for JS
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Growth {
+    Bounded = 0,   // still lifes, oscillators, spaceships, and patterns that die out
+    Linear = 1,    // guns and puffers
+    Quadratic = 2, // breeders
+}
+
+impl From<engine::growth::Growth> for Growth {
+    fn from(growth: engine::growth::Growth) -> Growth {
+        match growth {
+            engine::growth::Growth::Bounded => Growth::Bounded,
+            engine::growth::Growth::Linear => Growth::Linear,
+            engine::growth::Growth::Quadratic => Growth::Quadratic,
+        }
+    }
+}
+
+// result of Universe::analyze_growth, see engine::growth::GrowthRate
+#[wasm_bindgen]
+pub struct GrowthRate {
+    inner: engine::growth::GrowthRate,
+}
+
+#[wasm_bindgen]
+impl GrowthRate {
+    pub fn class(&self) -> Growth {
+        self.inner.class.into()
+    }
+
+    pub fn exponent(&self) -> f64 {
+        self.inner.exponent
+    }
+
+    // Float64Array [constant, per generation, per generation squared] fitted to the population
+    pub fn population(&self) -> Vec<f64> {
+        self.inner.population.to_vec()
+    }
+
+    // the same fit for the area of the bounding box
+    pub fn area(&self) -> Vec<f64> {
+        self.inner.area.to_vec()
+    }
+}
+
+// result of Universe::label_components, see engine::components::Components
+#[wasm_bindgen]
+pub struct Components {
+    inner: engine::components::Components,
+}
+
+#[wasm_bindgen]
+impl Components {
+    // Uint32Array with the component of every cell (numbered from 1, 0 where nothing's alive)
+    pub fn labels(&self) -> Vec<u32> {
+        self.inner.labels().to_vec()
+    }
+
+    // live cells per component, component id at index id - 1
+    pub fn sizes(&self) -> Vec<u32> {
+        self.inner.sizes().iter().map(|&size| size as u32).collect()
+    }
+
+    pub fn count(&self) -> usize {
+        self.inner.count()
+    }
+}
+
+#[wasm_bindgen]
+impl Universe {
+    // group touching live cells (diagonals included) into numbered components, e.g. to color
+    // colonies apart or count distinct objects
+    pub fn label_components(&self) -> Components {
+        Components {
+            inner: self.inner.label_components(),
+        }
+    }
+
+    // evolve a copy of the universe until the current pattern reappears (up to max_period
+    // generations) and report its period and displacement, undefined if it never does
+    pub fn measure_velocity(&self, max_period: u32) -> Option<Velocity> {
+        self.inner.measure_velocity(max_period).map(Velocity::from)
+    }
+
+    // run a copy of the universe on an unbounded plane until it settles into still lifes,
+    // oscillators and escaping gliders, undefined if that takes more than max_gens generations.
+    // the plane stops growing at 4096 cells a side: gliders that get that far are deleted and
+    // drop out of the final population, which then settles later than it would have
+    pub fn measure_lifespan(&self, max_gens: u64) -> Option<Lifespan> {
+        self.inner.measure_lifespan(max_gens).map(Lifespan::from)
+    }
+
+    // run a copy of the universe on an unbounded plane for generations generations, sampling
+    // the population and bounding box samples times, and classify its growth as bounded,
+    // linear (guns, puffers) or quadratic (breeders)
+    pub fn analyze_growth(&self, generations: u64, samples: u32) -> GrowthRate {
+        GrowthRate {
+            inner: self.inner.analyze_growth(generations, samples),
+        }
+    }
+
+    // Catagolue name of the live population (e.g. "xs4_33", "xq4_153") under the current rule,
+    // undefined if it doesn't repeat within max_period generations
+    pub fn apgcode(&self, max_period: u32) -> Option<String> {
+        self.inner.apgcode(max_period)
+    }
+
+    // every occurrence of an ASCII art pattern ('.' dead, 'O' alive) as flat [row, col, ...]
+    // pairs of its top-left corner, e.g. to highlight every glider on screen - with
+    // any_orientation rotated and reflected copies are found too
+    pub fn find_pattern(&self, pattern: &str, any_orientation: bool) -> Result<Vec<u32>, JsError> {
+        let pattern = engine::pattern::Pattern::from_string(pattern)?;
+        Ok(self
+            .inner
+            .find_pattern(&pattern, any_orientation)
+            .into_iter()
+            .flat_map(|(row, col)| [row, col])
+            .collect())
+    }
+}
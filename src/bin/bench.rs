@@ -0,0 +1,85 @@
+// headless generations/second report for every engine across grid sizes
+// run with `cargo run --release --features bench --bin bench [seconds per measurement]`
+//
+// for statistically rigorous numbers use the criterion suite: `cargo bench -p game-of-life-core`
+
+use std::env;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use game_of_life_core::continuous::ContinuousUniverse;
+use game_of_life_core::reaction_diffusion::ReactionDiffusion;
+use game_of_life_core::sand::{Material, SandUniverse};
+use game_of_life_core::random::Xorshift;
+use game_of_life_core::Universe;
+
+const SIZES: [u32; 4] = [64, 256, 512, 1024];
+
+fn main() {
+    let budget = env::args()
+        .nth(1)
+        .and_then(|arg| arg.parse::<f64>().ok())
+        .map_or(Duration::from_secs(1), Duration::from_secs_f64);
+
+    println!("{:<14} {:>6} {:>12} {:>14}", "engine", "size", "gens/sec", "Mcells/sec");
+
+    for &size in SIZES.iter() {
+        let mut universe = Universe::new(size, size);
+        universe.randomize();
+        report("life", size, measure(budget, || universe.tick(1)));
+    }
+
+    for &size in SIZES.iter() {
+        let mut universe = ContinuousUniverse::new(size, size);
+        universe.randomize(&mut rng());
+        report("smooth_conway", size, measure(budget, || universe.tick()));
+    }
+
+    // the lenia convolution is far heavier than the rest, skip the biggest grids
+    for &size in SIZES.iter().filter(|&&size| size <= 256) {
+        let mut universe = ContinuousUniverse::new(size, size);
+        universe.set_lenia(13, 0.15, 0.015, 0.1);
+        universe.randomize(&mut rng());
+        report("lenia", size, measure(budget, || universe.tick()));
+    }
+
+    for &size in SIZES.iter() {
+        let mut simulation = ReactionDiffusion::new(size, size);
+        simulation.seed(size / 2, size / 2, size / 8);
+        report("gray_scott", size, measure(budget, || simulation.tick()));
+    }
+
+    for &size in SIZES.iter() {
+        let mut sandbox = SandUniverse::new(size, size);
+        let mut rng = rng();
+        report(
+            "sand",
+            size,
+            measure(budget, || {
+                sandbox.paint(0, size / 2, size / 16, Material::Sand);
+                sandbox.tick(&mut rng);
+            }),
+        );
+    }
+}
+
+// run step until the budget is spent (at least once), returns generations per second
+fn measure<F: FnMut()>(budget: Duration, mut step: F) -> f64 {
+    let start = Instant::now();
+    let mut generations = 0u64;
+    while generations == 0 || start.elapsed() < budget {
+        step();
+        generations += 1;
+    }
+    generations as f64 / start.elapsed().as_secs_f64()
+}
+
+fn report(engine: &str, size: u32, gens_per_sec: f64) {
+    let cells_per_sec = gens_per_sec * (size as f64) * (size as f64) / 1e6;
+    println!("{:<14} {:>6} {:>12.1} {:>14.2}", engine, size, gens_per_sec, cells_per_sec);
+}
+
+// generator seeded from the clock, a different soup every run
+fn rng() -> Xorshift {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_nanos());
+    Xorshift::new(nanos as u64)
+}
@@ -0,0 +1,121 @@
+// native terminal frontend for the engine, run with `cargo run --features cli --bin life`
+//
+// keys: space pause/resume, n single step, r randomize, c clear, +/- speed, q quit
+
+use std::io::{self, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use game_of_life_core::{Cell, Universe};
+
+// delay between generations, adjusted with +/-
+const MIN_DELAY_MS: u64 = 10;
+const MAX_DELAY_MS: u64 = 1000;
+
+fn main() -> io::Result<()> {
+    let (columns, rows) = terminal::size()?;
+    // each character cell shows two universe rows using half blocks, the last line is the status bar
+    let width = columns.max(1) as u32;
+    let height = (rows.saturating_sub(1).max(1) as u32) * 2;
+
+    let mut universe = Universe::new(width, height);
+    universe.reseed(seed());
+    universe.randomize();
+
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+
+    let result = run(&mut universe, &mut stdout);
+
+    execute!(stdout, Show, LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+fn run(universe: &mut Universe, stdout: &mut io::Stdout) -> io::Result<()> {
+    let mut paused = false;
+    let mut delay_ms = 100;
+    let mut generation: u64 = 0;
+
+    loop {
+        render(universe, stdout, generation, paused, delay_ms)?;
+
+        // wait for input until the next generation is due
+        if event::poll(Duration::from_millis(delay_ms))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char(' ') => paused = !paused,
+                    KeyCode::Char('n') => {
+                        universe.tick(1);
+                        generation += 1;
+                    }
+                    KeyCode::Char('r') => {
+                        universe.randomize();
+                        generation = 0;
+                    }
+                    KeyCode::Char('c') => {
+                        universe.die();
+                        generation = 0;
+                    }
+                    KeyCode::Char('+') | KeyCode::Char('=') => delay_ms = (delay_ms / 2).max(MIN_DELAY_MS),
+                    KeyCode::Char('-') => delay_ms = (delay_ms * 2).min(MAX_DELAY_MS),
+                    _ => {}
+                }
+            }
+            continue;
+        }
+
+        if !paused {
+            universe.tick(1);
+            generation += 1;
+        }
+    }
+}
+
+fn render(universe: &Universe, stdout: &mut io::Stdout, generation: u64, paused: bool, delay_ms: u64) -> io::Result<()> {
+    queue!(stdout, MoveTo(0, 0))?;
+
+    for row in (0..universe.height()).step_by(2) {
+        let mut line = String::with_capacity(universe.width() as usize * 3);
+        for col in 0..universe.width() {
+            let top = universe.get_cell(row, col) == Some(Cell::Alive);
+            let bottom = universe.get_cell(row + 1, col) == Some(Cell::Alive);
+            line.push(match (top, bottom) {
+                (true, true) => '█',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (false, false) => ' ',
+            });
+        }
+        queue!(stdout, Print(line), Print("\r\n"))?;
+    }
+
+    let status = format!(
+        "gen {}  pop {}  density {:.3}  {}ms/gen  {}  [space] pause  [n] step  [r] random  [c] clear  [+/-] speed  [q] quit",
+        generation,
+        universe.population(),
+        universe.density(),
+        delay_ms,
+        if paused { "paused" } else { "running" },
+    );
+    // cut the status bar to the terminal width so it never scrolls the screen
+    let status: String = status.chars().take(universe.width() as usize).collect();
+    queue!(stdout, Clear(ClearType::CurrentLine), Print(status))?;
+
+    stdout.flush()
+}
+
+// seed from the clock, a different soup every run
+fn seed() -> u64 {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_nanos());
+    nanos as u64
+}
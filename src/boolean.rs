@@ -0,0 +1,32 @@
+use wasm_bindgen::prelude::*;
+
+use crate::Universe;
+
+// other is laid over this universe with its top-left at (row_offset, col_offset), the sizes
+// don't have to match - see engine::boolean
+#[wasm_bindgen]
+impl Universe {
+    // alive where either is
+    pub fn union(&mut self, other: &Universe, row_offset: i32, col_offset: i32) {
+        self.inner.union(&other.inner, row_offset as i64, col_offset as i64);
+    }
+
+    // alive where both are
+    pub fn intersect(&mut self, other: &Universe, row_offset: i32, col_offset: i32) {
+        self.inner.intersect(&other.inner, row_offset as i64, col_offset as i64);
+    }
+
+    // alive where exactly one is
+    pub fn xor(&mut self, other: &Universe, row_offset: i32, col_offset: i32) {
+        self.inner.xor(&other.inner, row_offset as i64, col_offset as i64);
+    }
+
+    // cells that differ between the two as flat [row, col, ...] pairs
+    pub fn diff_cells(&self, other: &Universe, row_offset: i32, col_offset: i32) -> Vec<u32> {
+        self.inner
+            .diff_cells(&other.inner, row_offset as i64, col_offset as i64)
+            .into_iter()
+            .flat_map(|(row, col)| [row, col])
+            .collect()
+    }
+}
@@ -0,0 +1,72 @@
+use wasm_bindgen::prelude::*;
+
+use crate::Universe;
+
+// buffers a Universe exposes over wasm memory
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layer {
+    Cells = 0,
+    Ages = 1,   // empty unless age tracking is on
+    Colors = 2, // RGBA image from the last render_rgba_aged
+    Heatmap = 3, // share of the time alive from the last render_heatmap
+    Exposure = 4, // generations alive per cell, empty unless exposure tracking is on
+}
+
+// typed array to view a buffer through
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ElementKind {
+    Uint8 = 0,
+    Uint32 = 1,
+    Float32 = 2,
+    Uint16 = 3,
+}
+
+impl ElementKind {
+    pub fn size(&self) -> u32 {
+        match self {
+            ElementKind::Uint8 => 1,
+            ElementKind::Uint16 => 2,
+            ElementKind::Uint32 | ElementKind::Float32 => 4,
+        }
+    }
+}
+
+// where a buffer lives in wasm memory and how to read it, enough for generic rendering code:
+// new Uint8Array(memory.buffer, byte_offset, length) for Uint8, and so on
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferDescriptor {
+    pub byte_offset: u32,
+    pub length: u32, // in elements, not bytes
+    pub kind: ElementKind,
+    pub stride: u32, // bytes from the start of one row to the next
+}
+
+impl BufferDescriptor {
+    pub fn new<T>(buffer: &[T], kind: ElementKind, width: u32) -> BufferDescriptor {
+        BufferDescriptor {
+            byte_offset: buffer.as_ptr() as usize as u32,
+            length: buffer.len() as u32,
+            kind,
+            stride: width * kind.size(),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Universe {
+    // location and layout of one of the universe's buffers, valid under the same rules as
+    // cells() - re-read it whenever buffer_generation changes or memory grows
+    pub fn buffer_descriptor(&self, layer: Layer) -> BufferDescriptor {
+        match layer {
+            Layer::Cells => BufferDescriptor::new(self.get_cells(), ElementKind::Uint8, self.width()),
+            Layer::Ages => BufferDescriptor::new(self.engine().ages().unwrap_or(&[]), ElementKind::Uint16, self.width()),
+            // four bytes per pixel
+            Layer::Colors => BufferDescriptor::new(self.engine().rgba(), ElementKind::Uint8, self.width() * 4),
+            Layer::Heatmap => BufferDescriptor::new(self.engine().heatmap(), ElementKind::Float32, self.width()),
+            Layer::Exposure => BufferDescriptor::new(self.engine().exposure().unwrap_or(&[]), ElementKind::Uint32, self.width()),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+
+// grids and kernels are plain rust, re-exported so custom kernels can be plugged in
+pub use engine::continuous::{FloatGrid, Kernel, SmoothConway};
+
+// built-in kernels that can be selected from JS, mirrors engine::continuous::EngineMode
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EngineMode {
+    SmoothConway = 0,
+    Lenia = 1,
+}
+
+impl From<EngineMode> for engine::continuous::EngineMode {
+    fn from(mode: EngineMode) -> engine::continuous::EngineMode {
+        match mode {
+            EngineMode::SmoothConway => engine::continuous::EngineMode::SmoothConway,
+            EngineMode::Lenia => engine::continuous::EngineMode::Lenia,
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub struct ContinuousUniverse {
+    inner: engine::continuous::ContinuousUniverse,
+}
+
+#[wasm_bindgen]
+impl ContinuousUniverse {
+    pub fn new(width: u32, height: u32) -> ContinuousUniverse {
+        crate::utils::set_panic_hook();
+        ContinuousUniverse {
+            inner: engine::continuous::ContinuousUniverse::new(width, height),
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.inner.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.inner.height()
+    }
+
+    // read-only pointer to the f32 values, view it from JS as a Float32Array of width * height
+    pub fn values(&self) -> *const f32 {
+        self.inner.values().as_ptr()
+    }
+
+    pub fn get_value(&self, row: u32, col: u32) -> f32 {
+        self.inner.get_value(row, col)
+    }
+
+    pub fn set_value(&mut self, row: u32, col: u32, value: f32) {
+        self.inner.set_value(row, col, value);
+    }
+
+    // switch to one of the built-in kernels with its default parameters
+    pub fn set_mode(&mut self, mode: EngineMode) {
+        self.inner.set_mode(mode.into());
+    }
+
+    // switch to Lenia with a custom kernel radius, growth function (mu, sigma) and time step
+    pub fn set_lenia(&mut self, radius: u32, mu: f32, sigma: f32, dt: f32) {
+        self.inner.set_lenia(radius, mu, sigma, dt);
+    }
+
+    // fill the grid with uniform random values in [0, 1)
+    pub fn randomize(&mut self) {
+        self.inner.randomize(&mut crate::utils::rng());
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    // apply the kernel to every cell (new generation)
+    pub fn tick(&mut self) {
+        self.inner.tick();
+    }
+}
+
+// rust-only api - kernels can't cross the wasm boundary
+impl ContinuousUniverse {
+    pub fn with_kernel(width: u32, height: u32, kernel: Box<dyn Kernel>) -> ContinuousUniverse {
+        ContinuousUniverse {
+            inner: engine::continuous::ContinuousUniverse::with_kernel(width, height, kernel),
+        }
+    }
+
+    pub fn set_kernel(&mut self, kernel: Box<dyn Kernel>) {
+        self.inner.set_kernel(kernel);
+    }
+
+    pub fn grid(&self) -> &FloatGrid {
+        self.inner.grid()
+    }
+
+    pub fn grid_mut(&mut self) -> &mut FloatGrid {
+        self.inner.grid_mut()
+    }
+}
@@ -0,0 +1,34 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::symmetry::Symmetry;
+use crate::{Cell, Universe};
+
+#[wasm_bindgen]
+impl Universe {
+    // mirror every edit (toggle, set, paint, line, stamp) so drawings stay symmetric
+    pub fn set_edit_symmetry(&mut self, symmetry: Symmetry) {
+        self.engine_mut().set_edit_symmetry(symmetry.into());
+    }
+
+    pub fn edit_symmetry(&self) -> Symmetry {
+        self.engine().edit_symmetry().into()
+    }
+
+    // fill a disc of the given radius around (row, col), wrapping around the edges that wrap
+    pub fn paint(&mut self, row: u32, col: u32, radius: u32, state: Cell) {
+        self.engine_mut().paint(row, col, radius, state.into());
+    }
+
+    // straight line of cells between two points, e.g. to join up a fast pointer drag
+    pub fn draw_line(&mut self, row0: u32, col0: u32, row1: u32, col1: u32, state: Cell) {
+        self.engine_mut().draw_line(row0, col0, row1, col1, state.into());
+    }
+
+    // bring an ASCII art pattern ('.' dead, 'O' alive) to life with its top-left at (row, col)
+    pub fn stamp(&mut self, pattern: &str, row: u32, col: u32) -> Result<(), JsError> {
+        let pattern = engine::pattern::Pattern::from_string(pattern)?;
+        self.engine_mut().stamp(&pattern, row, col);
+        Ok(())
+    }
+}
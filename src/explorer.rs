@@ -0,0 +1,36 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+
+// one explored rule, see engine::explorer::RuleScore
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleScore {
+    pub rule: String,
+    pub activity: f64,
+    pub growth: f64,
+    pub entropy: f64,
+    pub score: f64,
+}
+
+impl From<engine::explorer::RuleScore> for RuleScore {
+    fn from(score: engine::explorer::RuleScore) -> RuleScore {
+        RuleScore {
+            rule: score.rule,
+            activity: score.activity,
+            growth: score.growth,
+            entropy: score.entropy,
+            score: score.score,
+        }
+    }
+}
+
+// run count random B/S rules on one random width x height soup for generations each and
+// return them ranked by score, best first - feed the winners to add_rule_region or similar
+#[wasm_bindgen]
+pub fn explore_rules(count: usize, width: u32, height: u32, generations: u32) -> Vec<RuleScore> {
+    engine::explorer::explore_rules(count, width, height, generations, &mut crate::utils::rng())
+        .into_iter()
+        .map(RuleScore::from)
+        .collect()
+}
@@ -0,0 +1,39 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+#[wasm_bindgen]
+impl Universe {
+    // sum up where cells are alive every generation from now on, for export_long_exposure_rgba
+    pub fn set_track_exposure(&mut self, track: bool) {
+        self.inner.set_track_exposure(track);
+        self.check_memory();
+    }
+
+    pub fn exposed_generations(&self) -> u32 {
+        self.inner.exposed_generations()
+    }
+
+    // the run so far as one "long exposure" picture, 4 bytes (RGBA) per cell ready for an
+    // ImageData: dead for cells never alive, then colors from rarely to most often alive
+    // (packed 0xRRGGBBAA like set_palette)
+    pub fn export_long_exposure_rgba(&self, dead: u32, colors: Vec<u32>) -> Result<Vec<u8>, JsError> {
+        let palette = engine::age::Palette {
+            dead,
+            ages: colors,
+            ..self.inner.palette().clone()
+        };
+        Ok(self.inner.export_long_exposure_rgba(&palette)?)
+    }
+
+    // paint where cells have been alive into a buffer and return a pointer to it, one f32 per
+    // cell from 0 (never) to 1 (every exposed generation) - view it as a Float32Array, or bind
+    // it through buffer_descriptor(Layer::Heatmap)
+    pub fn render_heatmap(&mut self) -> *const f32 {
+        let ptr = self.inner.render_heatmap().as_ptr();
+        // the buffer is allocated on the first render, which can grow memory
+        self.check_memory();
+        ptr
+    }
+}
@@ -0,0 +1,24 @@
+use wasm_bindgen::prelude::*;
+
+use crate::Universe;
+
+#[wasm_bindgen]
+impl Universe {
+    // record population, births and deaths every generation in at most capacity samples
+    // (rounded up to an even number), merging samples as the run gets longer - 0 stops
+    // recording
+    pub fn set_population_history(&mut self, capacity: usize) {
+        self.inner.set_population_history(capacity);
+    }
+
+    // Float32Array of population, births, deaths per sample, oldest first, ready for a
+    // sparkline - empty when not recording
+    pub fn population_history(&self) -> Vec<f32> {
+        self.inner.population_history().map_or_else(Vec::new, |history| history.to_f32())
+    }
+
+    // generations covered by each sample of population_history
+    pub fn history_stride(&self) -> u32 {
+        self.inner.population_history().map_or(1, |history| history.stride())
+    }
+}
@@ -0,0 +1,50 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+// payload of the on_generation callback, see engine::hooks
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GenerationStats {
+    pub generation: u64, // a BigInt on the JS side
+    pub population: u32,
+    pub births: u32, // since the generation before
+    pub deaths: u32,
+}
+
+impl From<engine::hooks::GenerationStats> for GenerationStats {
+    fn from(stats: engine::hooks::GenerationStats) -> GenerationStats {
+        GenerationStats {
+            generation: stats.generation,
+            population: stats.population as u32,
+            births: stats.births,
+            deaths: stats.deaths,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Universe {
+    // call callback(stats) on every generation that is a multiple of interval, e.g. for
+    // autosave or progress reports - it runs once per such generation even when a tick steps
+    // past several, right after the tick call. the universe can't be used from inside the
+    // callback, defer work that needs it (export_state, ...) with queueMicrotask.
+    // undefined or an interval of 0 removes it
+    pub fn on_generation(&mut self, interval: u32, callback: Option<js_sys::Function>) {
+        let interval = if callback.is_some() { interval } else { 0 };
+        self.inner.set_hook_interval(interval);
+        self.on_generation = callback;
+    }
+
+    // run the on_generation callback for the generations stepped since it last ran
+    pub(crate) fn run_generation_hooks(&mut self) {
+        let events = self.inner.take_hook_events();
+        if let Some(callback) = &self.on_generation {
+            for stats in events {
+                // as with on_memory_change, a throwing callback doesn't stop the others
+                let _ = callback.call1(&JsValue::NULL, &GenerationStats::from(stats).into());
+            }
+        }
+    }
+}
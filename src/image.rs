@@ -0,0 +1,43 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+#[wasm_bindgen]
+impl Universe {
+    // one cell per pixel of a canvas ImageData (pass imageData.data), alive where the pixel
+    // is darker than threshold (0-255), transparent pixels count as white
+    pub fn from_image_data(width: u32, height: u32, rgba: &[u8], threshold: u8) -> Result<Universe, JsError> {
+        crate::utils::set_panic_hook();
+        Ok(Universe::from(engine::Universe::from_image_data(width, height, rgba, threshold)?))
+    }
+
+    // same as from_image_data straight from the bytes of a PNG file
+    #[cfg(feature = "png")]
+    pub fn from_png(bytes: &[u8], threshold: u8) -> Result<Universe, JsError> {
+        let (width, height, rgba) = decode_png(bytes).map_err(|err| JsError::new(&format!("invalid PNG: {}", err)))?;
+        Universe::from_image_data(width, height, &rgba, threshold)
+    }
+}
+
+// decode to 8-bit RGBA whatever the PNG's color type and bit depth
+#[cfg(feature = "png")]
+fn decode_png(bytes: &[u8]) -> Result<(u32, u32, Vec<u8>), png::DecodingError> {
+    use png::ColorType;
+
+    let mut decoder = png::Decoder::new(bytes);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info()?;
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let frame = reader.next_frame(&mut pixels)?;
+    pixels.truncate(frame.buffer_size());
+
+    let rgba = match frame.color_type {
+        ColorType::Rgba => pixels,
+        ColorType::Rgb => pixels.chunks_exact(3).flat_map(|p| [p[0], p[1], p[2], 255]).collect(),
+        ColorType::GrayscaleAlpha => pixels.chunks_exact(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+        // indexed images are expanded by normalize_to_color8
+        ColorType::Grayscale | ColorType::Indexed => pixels.iter().flat_map(|&p| [p, p, p, 255]).collect(),
+    };
+    Ok((frame.width, frame.height, rgba))
+}
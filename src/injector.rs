@@ -0,0 +1,47 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+// mirrors engine::injector::Injection for JS
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Injection {
+    Glider = 0, // always heading into the grid
+    Soup = 1,   // random cells in a small square
+}
+
+impl From<Injection> for engine::injector::Injection {
+    fn from(injection: Injection) -> engine::injector::Injection {
+        match injection {
+            Injection::Glider => engine::injector::Injection::Glider,
+            Injection::Soup => engine::injector::Injection::Soup,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Universe {
+    // "rain" for screensavers: drop a glider or small soup in along a random edge every
+    // interval generations, never onto or next to live cells - soups are 5x5 and half
+    // alive unless set_rain_soup says otherwise
+    pub fn start_rain(&mut self, interval: u32, kind: Injection) {
+        let injector = engine::injector::Injector::new(interval, kind.into());
+        self.inner.set_injector(Some(injector));
+    }
+
+    pub fn stop_rain(&mut self) {
+        self.inner.set_injector(None);
+    }
+
+    pub fn set_rain_soup(&mut self, size: u32, density: f64) -> Result<(), JsError> {
+        let injector = self.inner.injector_mut().ok_or_else(|| JsError::new("rain is not started"))?;
+        injector.set_soup(size, density);
+        Ok(())
+    }
+
+    // drop one in now, false when rain is off or there was no clear spot
+    pub fn inject(&mut self) -> bool {
+        self.inner.inject()
+    }
+}
@@ -0,0 +1,112 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Cell;
+
+// mirrors engine::layers::LayerId for JS
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayerId {
+    A = 0,
+    B = 1,
+}
+
+impl From<LayerId> for engine::layers::LayerId {
+    fn from(id: LayerId) -> engine::layers::LayerId {
+        match id {
+            LayerId::A => engine::layers::LayerId::A,
+            LayerId::B => engine::layers::LayerId::B,
+        }
+    }
+}
+
+// mirrors engine::layers::Overlap for JS
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overlap {
+    Ignore = 0,
+    Die = 1,     // cells covered by the other layer die
+    Survive = 2, // live cells covered by the other layer never die
+}
+
+impl From<Overlap> for engine::layers::Overlap {
+    fn from(overlap: Overlap) -> engine::layers::Overlap {
+        match overlap {
+            Overlap::Ignore => engine::layers::Overlap::Ignore,
+            Overlap::Die => engine::layers::Overlap::Die,
+            Overlap::Survive => engine::layers::Overlap::Survive,
+        }
+    }
+}
+
+// two overlaid universes reacting to each other, see engine::layers
+#[wasm_bindgen]
+pub struct LayeredUniverse {
+    inner: engine::layers::LayeredUniverse,
+}
+
+#[wasm_bindgen]
+impl LayeredUniverse {
+    pub fn new(width: u32, height: u32) -> LayeredUniverse {
+        crate::utils::set_panic_hook();
+        let mut inner = engine::layers::LayeredUniverse::new(width, height);
+        for layer in [engine::layers::LayerId::A, engine::layers::LayerId::B] {
+            inner.layer_mut(layer).reseed(crate::utils::seed());
+        }
+        LayeredUniverse { inner }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.inner.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.inner.height()
+    }
+
+    // read-only pointer to one layer's cells, one byte per cell like Universe.cells()
+    pub fn cells(&self, layer: LayerId) -> *const Cell {
+        // engine::Cell and Cell share the same single byte representation
+        self.inner.layer(layer.into()).get_cells().as_ptr() as *const Cell
+    }
+
+    pub fn set_cell(&mut self, layer: LayerId, row: u32, col: u32, state: Cell) {
+        self.inner.layer_mut(layer.into()).set_cell(row, col, state.into());
+    }
+
+    pub fn toggle_cell(&mut self, layer: LayerId, row: u32, col: u32) {
+        self.inner.layer_mut(layer.into()).toggle_cell(row, col);
+    }
+
+    // B/S rule (e.g. "B36/S23") for one layer
+    pub fn set_rule(&mut self, layer: LayerId, rule: &str) -> Result<(), JsError> {
+        let table = engine::rules::parse_rule(rule)?;
+        Ok(self.inner.layer_mut(layer.into()).set_rule_table(&table)?)
+    }
+
+    pub fn set_overlap(&mut self, layer: LayerId, overlap: Overlap) {
+        self.inner.set_overlap(layer.into(), overlap.into());
+    }
+
+    // random soup on one layer, each cell alive with probability density
+    pub fn randomize(&mut self, layer: LayerId, density: f64) {
+        self.inner
+            .layer_mut(layer.into())
+            .randomize_symmetric(density, engine::symmetry::Symmetry::None);
+    }
+
+    pub fn tick(&mut self) {
+        self.inner.tick();
+    }
+}
+
+// rust-only access to the underlying layers
+impl LayeredUniverse {
+    pub fn engine(&self) -> &engine::layers::LayeredUniverse {
+        &self.inner
+    }
+
+    pub fn engine_mut(&mut self) -> &mut engine::layers::LayeredUniverse {
+        &mut self.inner
+    }
+}
@@ -0,0 +1,21 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+#[wasm_bindgen]
+impl Universe {
+    // stamp a named pattern from the bundled Life Lexicon (e.g. "rpentomino", "Gosper glider
+    // gun") with its top-left at (row, col), throws for names it doesn't have. the apgcodes of
+    // small still lifes and oscillators from the bundled census (e.g. "xs6_696") work too
+    pub fn insert_named(&mut self, name: &str, row: u32, col: u32) -> Result<(), JsError> {
+        Ok(self.inner.insert_named(name, row, col)?)
+    }
+}
+
+// names of the lexicon patterns matching a query, ignoring case, spaces and punctuation -
+// an empty query lists them all
+#[wasm_bindgen]
+pub fn search_lexicon(query: &str) -> Vec<String> {
+    engine::lexicon::search(query).into_iter().map(String::from).collect()
+}
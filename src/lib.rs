@@ -1,10 +1,41 @@
 mod utils;
+mod snapshot;
+mod age;
+mod editing;
+mod walls;
+mod image;
+mod exposure;
+mod history;
+mod life_history;
+mod boolean;
+#[cfg(feature = "lexicon")]
+pub mod lexicon;
+#[cfg(feature = "render-bench")]
+pub mod render_bench;
+pub mod analysis;
+pub mod buffers;
+pub mod sonify;
+pub mod explorer;
+pub mod layers;
+pub mod hooks;
+pub mod injector;
+pub mod runner;
+pub mod presets;
+pub mod continuous;
+pub mod reaction_diffusion;
+pub mod sand;
+pub mod symmetry;
+pub mod preview;
+pub mod region;
+pub mod viewport;
+
+// the simulation itself lives in the platform-agnostic core crate, this crate only
+// wraps it for JS
+pub use game_of_life_core as engine;
+pub use engine::{lenia, rules};
 
 use wasm_bindgen::prelude::*;
-use rand::Rng;
-// use fixedbitset::FixedBitSet;
-// use std::fmt;
-use web_sys;
+use symmetry::Symmetry;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
 // allocator.
@@ -15,6 +46,7 @@ static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 // wasm_bindgen used to interface with JS
 // importing JS functions to rust
 // indexing into the global JS namespace/object table to find the alert function and bring it into rust/scope
+#[cfg(feature = "console")]
 #[wasm_bindgen]
 extern {
     // fn alert(s: &str);
@@ -22,28 +54,75 @@ extern {
     fn log(s: &str);
 }
 
+#[wasm_bindgen]
+extern {
+    // high resolution clock in milliseconds, available in windows, workers and node
+    #[wasm_bindgen(js_namespace = performance)]
+    fn now() -> f64;
+}
+
 // // exporting rust functions to JS
-// // placing the greet function in the heap (boxing) 
+// // placing the greet function in the heap (boxing)
 // // and creating a JS class wrapper around the pointer to the function to use within JS
 // #[wasm_bindgen]
 // pub fn greet(name: &str) {
 //     alert(&format!("Hello, {}!", name));
 // }
 
+// mirrors engine::Cell with the same byte layout, so the engine's cell buffer can be
+// handed to JS as-is
 #[wasm_bindgen]
 #[repr(u8)] // each cell is represented by a single byte when compiled to wasm, for memory efficiency
 #[derive(Clone, Copy, Debug, PartialEq, Eq)] // derive some traits
 pub enum Cell {
-    Dead = 0, // optimization 
+    Dead = 0, // optimization
     Alive = 1,
+    Wall = 2, // never changes, see walls.rs
+}
+
+impl From<Cell> for engine::Cell {
+    fn from(cell: Cell) -> engine::Cell {
+        match cell {
+            Cell::Dead => engine::Cell::Dead,
+            Cell::Alive => engine::Cell::Alive,
+            Cell::Wall => engine::Cell::Wall,
+        }
+    }
 }
 
-impl Cell {
-    fn toggle(&mut self) {
-        *self = match *self {
-            Cell::Dead => Cell::Alive,
-            Cell::Alive => Cell::Dead,
-        };
+impl From<engine::Cell> for Cell {
+    fn from(cell: engine::Cell) -> Cell {
+        match cell {
+            engine::Cell::Dead => Cell::Dead,
+            engine::Cell::Alive => Cell::Alive,
+            engine::Cell::Wall => Cell::Wall,
+        }
+    }
+}
+
+// mirrors engine::bounds::Strictness for JS
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strictness {
+    Clamp = 0,  // snap coordinates past the edge to the nearest edge cell
+    Strict = 1, // throw on coordinates past the edge
+}
+
+impl From<Strictness> for engine::bounds::Strictness {
+    fn from(strictness: Strictness) -> engine::bounds::Strictness {
+        match strictness {
+            Strictness::Clamp => engine::bounds::Strictness::Clamp,
+            Strictness::Strict => engine::bounds::Strictness::Strict,
+        }
+    }
+}
+
+impl From<engine::bounds::Strictness> for Strictness {
+    fn from(strictness: engine::bounds::Strictness) -> Strictness {
+        match strictness {
+            engine::bounds::Strictness::Clamp => Strictness::Clamp,
+            engine::bounds::Strictness::Strict => Strictness::Strict,
+        }
     }
 }
 
@@ -56,246 +135,360 @@ impl Cell {
 
 #[wasm_bindgen]
 pub struct Universe {
-    width: u32,
-    height: u32,
-    cells: Vec<Cell>,
-    // cells: FixedBitSet, // of length width * height (area)
+    inner: engine::Universe,
+    // called when some JS view over the buffers may have gone stale, see on_memory_change
+    on_memory_change: Option<js_sys::Function>,
+    // memory size and cell buffer generation when the callback last ran
+    seen_memory: (usize, u64),
+    // called every few generations, see on_generation
+    on_generation: Option<js_sys::Function>,
 }
- 
+
 #[wasm_bindgen]
 impl Universe {
     pub fn new(width: u32, height: u32) -> Universe {
         // initialize hook to console error out panics for debugging
         utils::set_panic_hook();
 
-        // let width = 64;
-        // let height = 64;
-        // let size = (width * height) as usize;
-        // use a set of bits to represent each cell, true or false, 1 or 0
-        // let mut cells = FixedBitSet::with_capacity(size);
-
-        let mut rng = rand::thread_rng();
-        
-        // fill up universe with cells, both alive and dead
-        let cells = (0..width * height)
-            .map(|_| {
-                let rand_num = rng.gen_range(0..2);
-                if rand_num == 1 {
-                    Cell::Alive
-                } else {
-                    Cell::Dead
-                }
-            })
-            .collect();
-
-        // for i  in 0..size {
-        //     let rand_num = rng.gen_range(0..2);
-        //     // 50% percent chance of alive or dead
-        //     cells.set(i, rand_num == 1);
-        // }
+        let mut inner = engine::Universe::new(width, height);
+        inner.reseed(utils::seed());
+        inner.randomize();
 
         // log(&format!("using imported console.log"));
         // web_sys::console::log_1(&format!("using web-sys").into());
         // log!("using macro wrapper");
 
-        Universe {
-           width,
-           height,
-           cells,
-        }
+        Universe::from(inner)
     }
 
     pub fn toggle_cell(&mut self, row: u32, col: u32) {
-        let idx = self.get_index(row, col);
-        self.cells[idx].toggle();
+        self.inner.toggle_cell(row, col);
     }
 
-    pub fn set_width(&mut self, width: u32) {
-        self.width = width;
+    // put a cell into a specific state, unlike toggle_cell this is safe to repeat while dragging
+    pub fn set_cell(&mut self, row: u32, col: u32, state: Cell) {
+        self.inner.set_cell(row, col, state.into());
+    }
 
-        // reset universe, no alive cells
-        // for i in 0..(width * self.height) as usize {
-        //     self.cells.set(i, false);
-        // }
+    // toggle_cell and set_cell for untrusted coordinates (e.g. clicks near the canvas edge),
+    // these clamp or throw depending on set_strictness instead of corrupting other cells
+    pub fn toggle_cell_checked(&mut self, row: u32, col: u32) -> Result<(), JsError> {
+        Ok(self.inner.toggle_cell_checked(row, col)?)
+    }
 
-        for i in 0..(width * self.height) as usize {
-            self.cells[i] = Cell::Dead;
-        }
+    pub fn set_cell_checked(&mut self, row: u32, col: u32, state: Cell) -> Result<(), JsError> {
+        Ok(self.inner.set_cell_checked(row, col, state.into())?)
+    }
+
+    pub fn set_strictness(&mut self, strictness: Strictness) {
+        self.inner.set_strictness(strictness.into());
+    }
+
+    pub fn strictness(&self) -> Strictness {
+        self.inner.strictness().into()
+    }
+
+    // set many cells in one call, coords is a flat array of row/col pairs: [r0, c0, r1, c1, ...]
+    pub fn set_cells_flat(&mut self, coords: &[u32], state: Cell) -> Result<(), JsError> {
+        Ok(self.inner.set_cells_flat(coords, state.into())?)
+    }
+
+    // state of a single cell, undefined in JS when the coordinates are outside the universe
+    pub fn get_cell(&self, row: u32, col: u32) -> Option<Cell> {
+        self.inner.get_cell(row, col).map(Cell::from)
+    }
+
+    // resizing kills every cell and reallocates the buffer (see buffer_generation)
+    pub fn set_width(&mut self, width: u32) {
+        self.inner.set_width(width);
+        self.check_memory();
     }
 
     pub fn width(&self) -> u32 {
-        self.width
+        self.inner.width()
     }
 
     pub fn set_height(&mut self, height: u32) {
-        self.height = height;
-
-        for i in 0..(self.width * height) as usize {
-            // self.cells.set(i, false);
-            self.cells[i] = Cell::Dead;
-        }
+        self.inner.set_height(height);
+        self.check_memory();
     }
 
     pub fn height(&self) -> u32 {
-        self.height
+        self.inner.height()
+    }
+
+    // enable wrapping independently per axis, e.g. (true, false) is a horizontal cylinder
+    pub fn set_wrap(&mut self, x: bool, y: bool) {
+        self.inner.set_wrap(x, y);
+    }
+
+    pub fn wrap_x(&self) -> bool {
+        self.inner.wrap_x()
+    }
+
+    pub fn wrap_y(&self) -> bool {
+        self.inner.wrap_y()
+    }
+
+    // twisted torus: crossing the right edge lands row_shift rows further down, crossing the
+    // bottom edge col_shift columns further right - throws if both are non-zero
+    pub fn set_twist(&mut self, row_shift: i32, col_shift: i32) -> Result<(), JsError> {
+        Ok(self.inner.set_twist(row_shift, col_shift)?)
+    }
+
+    // [row_shift, col_shift]
+    pub fn twist(&self) -> Vec<i32> {
+        let (row_shift, col_shift) = self.inner.twist();
+        vec![row_shift, col_shift]
+    }
+
+    // replace the rule with an arbitrary (possibly non-totalistic) table of 512 next states,
+    // indexed by the 9-bit neighborhood described in rules.rs - any non-zero entry means alive
+    pub fn set_rule_table(&mut self, table: &[u8]) -> Result<(), JsError> {
+        Ok(self.inner.set_rule_table(table)?)
+    }
+
+    pub fn rule_table(&self) -> Vec<u8> {
+        self.inner.rule_table().to_vec()
+    }
+
+    // run a different B/S rule (e.g. "B1357/S1357") inside a rectangle, bounds inclusive
+    pub fn add_rule_region(&mut self, row0: u32, col0: u32, row1: u32, col1: u32, rule: &str) -> Result<(), JsError> {
+        Ok(self.inner.add_rule_region(row0, col0, row1, col1, rule)?)
+    }
+
+    pub fn clear_rule_regions(&mut self) {
+        self.inner.clear_rule_regions();
     }
 
     // return a read-only pointer to a Cell type
     // raw pointer - unsafe access to memory location (unsafe Rust, bypass borrow checker)
+    // stays valid across ticks, but not across resizes or imports - see buffer_generation
     pub fn cells(&self) -> *const Cell {
-        self.cells.as_ptr()
+        self.get_cells().as_ptr()
+    }
+
+    // same as cells, named for hosts that reason about the front/back buffer pair
+    pub fn front_buffer_ptr(&self) -> *const Cell {
+        self.cells()
+    }
+
+    // changes whenever the cell buffer is reallocated, compare it before reusing a
+    // Uint8Array view over cells() and rebuild the view when it differs
+    pub fn buffer_generation(&self) -> u64 {
+        self.inner.buffer_generation()
+    }
+
+    // register a callback run whenever wasm memory has grown or the cell buffer was
+    // reallocated, either of which leaves typed-array views over cells() stale - rebuild
+    // them in the callback. checked after ticks, resizes and imports, or on check_memory
+    pub fn on_memory_change(&mut self, callback: Option<js_sys::Function>) {
+        self.on_memory_change = callback;
+        self.seen_memory = (utils::memory_pages(), self.inner.buffer_generation());
+    }
+
+    // run the on_memory_change callback if memory grew or the buffer moved since it last ran
+    pub fn check_memory(&mut self) {
+        let current = (utils::memory_pages(), self.inner.buffer_generation());
+        if current == self.seen_memory {
+            return;
+        }
+        self.seen_memory = current;
+        if let Some(callback) = &self.on_memory_change {
+            // a throwing callback is the host's problem, the universe is fine either way
+            let _ = callback.call0(&JsValue::NULL);
+        }
     }
-    // pub fn cells(&self) -> *const u32 {
-    //     self.cells.as_slice().as_ptr()
-    // }
 
     // pub fn render(&self) -> String {
     //     // provided by implementing the Display trait
     //     self.to_string()
     // }
 
-    // translate a 2d coordinate into a 1d index
-    fn get_index(&self, row: u32, column: u32) -> usize {
-        (row * self.width + column) as usize
-    }
-
-    // computes total live neighbors for a given cell
-    fn live_neighbor_count(&self, row: u32, column: u32) -> u8 {
-        // count the number of live neighbors
-        let mut count = 0;
-        // iterate over all possible neighbors
-        for delta_row in [self.height - 1, 0, 1].iter().cloned() {
-            for delta_col in [self.width - 1, 0, 1].iter().cloned() {
-                // don't count the cell itself
-                if delta_row == 0 && delta_col == 0 {
-                    continue;
-                }
-                // compute the neighbor's row and column
-                let neighbor_row = (row + delta_row) % self.height;
-                let neighbor_col = (column + delta_col) % self.width;
-                // get the index of the neighbor
-                let idx = self.get_index(neighbor_row, neighbor_col);
-                // increment the count if the neighbor is alive
-                count += self.cells[idx] as u8;
-            }
-        }
+    // computes total live neighbors for a given cell, honoring the wrap settings
+    pub fn live_neighbor_count(&self, row: u32, column: u32) -> u8 {
+        self.inner.live_neighbor_count(row, column)
+    }
 
-        count
+    // live neighbor count of every cell in row-major order, same layout as cells()
+    pub fn neighbor_counts(&self) -> Vec<u8> {
+        self.inner.neighbor_counts()
     }
 
-    // update the universe state with new cells (new generation)
+    // update the universe state with new cells (new generation), tick_per_frame times
     pub fn tick(&mut self, tick_per_frame: usize) {
-        let mut next = self.cells.clone();
-
-        // iterate over all cells
-        for row in 0..self.height {
-            for col in 0..self.width {
-                let idx = self.get_index(row, col); // get the index of the cell
-                let cell = self.cells[idx]; // get the cell
-
-                for _ in 0..tick_per_frame {
-                    let live_neighbors = self.live_neighbor_count(row, col); // get the number of live neighbors
-                    
-                    let next_cell = match (cell, live_neighbors) {
-                        // Rule 1: Any live cell with fewer than two live neighbours
-                        // dies, as if caused by underpopulation.
-                        (Cell::Alive, x) if x < 2 => Cell::Dead,
-                        // Rule 2: Any live cell with two or three live neighbours
-                        // lives on to the next generation.
-                        (Cell::Alive, 2) | (Cell::Alive, 3) => Cell::Alive,
-                        // Rule 3: Any live cell with more than three live
-                        // neighbours dies, as if by overpopulation.
-                        (Cell::Alive, x) if x > 3 => Cell::Dead,
-                        // Rule 4: Any dead cell with exactly three live neighbours
-                        // becomes a live cell, as if by reproduction.
-                        (Cell::Dead, 3) => Cell::Alive,
-                         // All other cells remain in the same state.
-                        (otherwise, _) => otherwise, 
-                    };
-    
-                    next[idx] = next_cell;
-    
-                    // match for an argument value!
-                    // next.set(idx, match (cell, live_neighbors) {
-                    //     (true, x) if x < 2 => false,
-                    //     (true, 2) | (true, 3) => true,
-                    //     (true, x) if x > 3 => false,
-                    //     (false, 3) => true,
-                    //     (otherwise, _) => otherwise,
-                    // });
-    
-                    // if cell != next[idx] {
-                    //     log!("cell flipped, alive to dead, dead to alive");
-                    // }
-                }
-            }
-        }
+        self.inner.tick(tick_per_frame);
+        self.check_memory();
+        self.run_generation_hooks();
+    }
+
+    // jump many generations forward in one call, without per-generation FFI crossings
+    // (u64 is a BigInt on the JS side)
+    pub fn advance(&mut self, generations: u64) {
+        self.inner.advance(generations);
+        self.check_memory();
+        self.run_generation_hooks();
+    }
+
+    // run as many generations as fit in budget_ms (measured with performance.now) and return
+    // how many that was, so a frame can spend a fixed slice of time simulating
+    pub fn tick_for(&mut self, budget_ms: f64) -> u32 {
+        let start = now();
+        let generations = self.inner.advance_while(|| now() - start < budget_ms);
+        self.check_memory();
+        self.run_generation_hooks();
+        generations as u32
+    }
+
+    // run until fewer than population cells are alive and return how many generations that
+    // took, undefined if it still hadn't after max_generations - one call for scripted
+    // experiments like "how long until this soup dies out?" (u64 is a BigInt on the JS side)
+    pub fn tick_until_population_below(&mut self, population: u32, max_generations: u64) -> Option<u64> {
+        let generations = self.inner.tick_until_population_below(population as usize, max_generations);
+        self.check_memory();
+        self.run_generation_hooks();
+        generations
+    }
+
+    // same for more than population cells alive
+    pub fn tick_until_population_above(&mut self, population: u32, max_generations: u64) -> Option<u64> {
+        let generations = self.inner.tick_until_population_above(population as usize, max_generations);
+        self.check_memory();
+        self.run_generation_hooks();
+        generations
+    }
+
+    // compute at most rows_budget rows of the next generation, returns true once the
+    // generation is complete and applied - keeps each call under a frame on huge universes
+    pub fn tick_partial(&mut self, rows_budget: u32) -> bool {
+        let done = self.inner.tick_partial(rows_budget);
+        self.check_memory();
+        self.run_generation_hooks();
+        done
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.inner.generation()
+    }
+
+    pub fn tick_in_progress(&self) -> bool {
+        self.inner.tick_in_progress()
+    }
+
+    // [min_row, min_col, max_row, max_col] of the live cells (inclusive), undefined when
+    // everything is dead
+    pub fn bounding_box(&self) -> Option<Vec<u32>> {
+        self.inner
+            .bounding_box()
+            .map(|b| vec![b.min_row, b.min_col, b.max_row, b.max_col])
+    }
+
+    // the universe as lines of braille characters, each covering 2x4 cells
+    pub fn render_braille(&self) -> String {
+        self.inner.render_braille()
+    }
 
-        self.cells = next;
+    // hash of the live population that ignores position, rotation and reflection - two
+    // universes holding the same object anywhere, in any orientation, hash the same
+    pub fn canonical_hash(&self) -> u64 {
+        self.inner.canonical_hash()
+    }
+
+    // areas changed by the last tick as flat [row, col, width, height, ...] quads, so only
+    // those need redrawing
+    pub fn dirty_rects(&self) -> Vec<u32> {
+        self.inner
+            .dirty_rects()
+            .iter()
+            .flat_map(|r| [r.row, r.col, r.width, r.height])
+            .collect()
+    }
+
+    pub fn population(&self) -> usize {
+        self.inner.population()
+    }
+
+    // fraction of cells that are alive
+    pub fn density(&self) -> f64 {
+        self.inner.density()
+    }
+
+    // shannon entropy in bits of the block_size x block_size patterns in the grid (1 to 5)
+    pub fn block_entropy(&self, block_size: u32) -> Result<f64, JsError> {
+        Ok(self.inner.block_entropy(block_size)?)
+    }
+
+    // resize the universe to the live cells plus margin on every side, moving the pattern
+    // to match - returns false when there is nothing alive to crop to
+    pub fn crop_to_pattern(&mut self, margin: u32) -> bool {
+        let cropped = self.inner.crop_to_pattern(margin);
+        self.check_memory();
+        cropped
+    }
+
+    // cross-check the fast tick against the naive reference stepper for some generations,
+    // throws with the first divergent generation and cell (the universe itself is unchanged)
+    pub fn verify_against_reference(&self, generations: usize) -> Result<(), JsError> {
+        Ok(self.inner.verify_against_reference(generations)?)
+    }
+
+    // step copies with tick, tick_partial and the reference stepper for some generations and
+    // throw naming the first backend to disagree with tick, where and when (for tests)
+    pub fn assert_backends_agree(&self, generations: usize) -> Result<(), JsError> {
+        Ok(self.inner.assert_backends_agree(generations)?)
     }
 
     pub fn reset(&mut self) {
-        let cells = (0..self.width * self.height)
-                        .map(|_| {
-                            let mut rng = rand::thread_rng();
-                            let state = rng.gen_range(0..2);
-                            if state == 1 {
-                                Cell::Alive
-                            } else {
-                                Cell::Dead
-                            }
-                        })
-                        .collect();
-        self.cells = cells;
+        self.inner.randomize();
+    }
+
+    // restart the generator behind reset, randomize_symmetric, the noisy fill presets and rain,
+    // so a run can be replayed exactly - new universes are seeded from Math.random
+    pub fn reseed(&mut self, seed: u64) {
+        self.inner.reseed(seed);
+    }
+
+    // random soup where each cell is alive with probability density, kept perfectly symmetric
+    pub fn randomize_symmetric(&mut self, density: f64, symmetry: Symmetry) {
+        self.inner.randomize_symmetric(density, symmetry.into());
     }
 
     pub fn die(&mut self) {
-        let cells = (0..self.width * self.height)
-                        .map(|_| Cell::Dead)
-                        .collect();
-        self.cells = cells;
+        self.inner.die();
     }
 }
 
 // implementations used for testing - no wasm_bindgen
 impl Universe {
-    // pub fn get_cells(&self) -> &FixedBitSet {
-    //     &self.cells
-    // }
-
-    pub fn get_cells(&self) -> &Vec<Cell> {
-        &self.cells
+    pub fn get_cells(&self) -> &[Cell] {
+        let cells = self.inner.get_cells();
+        // safe: engine::Cell and Cell are both repr(u8) with the same discriminants
+        unsafe { std::slice::from_raw_parts(cells.as_ptr() as *const Cell, cells.len()) }
     }
 
     // cells is a reference to a slice of tuples of 2 elements - row, col
     pub fn set_cells(&mut self, cells: &[(u32, u32)]) {
-        for (row, col) in cells.iter().cloned() {
-            let idx = self.get_index(row, col);
-            // set specific cells to alive (turn on)
-            // self.cells.set(idx, true);
-            self.cells[idx] = Cell::Alive;
-        }
+        self.inner.set_cells(cells);
+    }
+
+    // the wrapped engine, for rust code that wants the full core api
+    pub fn engine(&self) -> &engine::Universe {
+        &self.inner
+    }
+
+    pub fn engine_mut(&mut self) -> &mut engine::Universe {
+        &mut self.inner
     }
 }
 
-// implement Display trait to convert to String for printing
-// impl fmt::Display for Universe {
-//     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-//         // get the slice of the vector that contains the data, don't want entire growable vector
-//         // divide the vector of cells into lines of length the width of the universe (creating row)
-//         for line in self.cells.as_slice().chunks(self.width as usize) {
-//             for &cell in line {
-//                 // let symbol = if cell == Cell::Dead {
-//                 let symbol = if cell == 0 {
-//                     '◻'
-//                 } else {
-//                     '◼'
-//                 };
-//                 write!(f, "{}", symbol)?;
-//             }
-//             write!(f, "\n")?;
-//         }
-
-//         Ok(())
-//     }
-// }
\ No newline at end of file
+impl From<engine::Universe> for Universe {
+    fn from(inner: engine::Universe) -> Universe {
+        let seen_memory = (utils::memory_pages(), inner.buffer_generation());
+        Universe {
+            inner,
+            on_memory_change: None,
+            seen_memory,
+            on_generation: None,
+        }
+    }
+}
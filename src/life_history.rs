@@ -0,0 +1,53 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+#[wasm_bindgen]
+impl Universe {
+    // Golly's LifeHistory: cells remember having been alive and can be marked, drawn in
+    // their own colors by render_rgba_aged - turning it off forgets both
+    pub fn set_life_history(&mut self, enabled: bool) {
+        self.inner.set_life_history(enabled);
+    }
+
+    pub fn has_life_history(&self) -> bool {
+        self.inner.has_life_history()
+    }
+
+    // turns LifeHistory on if it's off
+    pub fn set_marked(&mut self, row: u32, col: u32, marked: bool) -> Result<(), JsError> {
+        Ok(self.inner.set_marked(row, col, marked)?)
+    }
+
+    // restart the envelope from the live cells, marks stay
+    pub fn clear_envelope(&mut self) {
+        self.inner.clear_envelope();
+    }
+
+    // Uint8Array with the LifeHistory state (0-6) of every cell, undefined when it's off
+    pub fn life_history_states(&self) -> Option<Vec<u8>> {
+        self.inner.life_history_states()
+    }
+
+    pub fn to_life_history_rle(&self) -> String {
+        self.inner.to_life_history_rle()
+    }
+
+    // a universe in LifeHistory mode, sized by the RLE header
+    pub fn from_life_history_rle(text: &str) -> Result<Universe, JsError> {
+        crate::utils::set_panic_hook();
+        Ok(Universe::from(engine::Universe::from_life_history_rle(text)?))
+    }
+
+    // colors of dead cells that were alive, and of marked live and dead cells, packed 0xRRGGBBAA
+    pub fn set_life_history_colors(&mut self, envelope: u32, marked_alive: u32, marked_dead: u32) -> Result<(), JsError> {
+        let palette = engine::age::Palette {
+            envelope,
+            marked_alive,
+            marked_dead,
+            ..self.inner.palette().clone()
+        };
+        Ok(self.inner.set_palette(palette)?)
+    }
+}
@@ -0,0 +1,41 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{engine, Universe};
+
+// procedurally generated starting conditions, mirrors engine::presets::FillPreset for JS
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillPreset {
+    Checkerboard = 0,
+    HorizontalStripes = 1,
+    VerticalStripes = 2,
+    DiagonalStripes = 3,
+    ConcentricRings = 4,
+    CentralBlob = 5, // dense random disc in the middle, dead everywhere else
+    SparseNoise = 6, // 10% alive
+    DenseNoise = 7,  // 50% alive
+}
+
+impl From<FillPreset> for engine::presets::FillPreset {
+    fn from(preset: FillPreset) -> engine::presets::FillPreset {
+        use engine::presets::FillPreset as Preset;
+        match preset {
+            FillPreset::Checkerboard => Preset::Checkerboard,
+            FillPreset::HorizontalStripes => Preset::HorizontalStripes,
+            FillPreset::VerticalStripes => Preset::VerticalStripes,
+            FillPreset::DiagonalStripes => Preset::DiagonalStripes,
+            FillPreset::ConcentricRings => Preset::ConcentricRings,
+            FillPreset::CentralBlob => Preset::CentralBlob,
+            FillPreset::SparseNoise => Preset::SparseNoise,
+            FillPreset::DenseNoise => Preset::DenseNoise,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Universe {
+    // replace every cell with the given preset, walls are kept
+    pub fn fill(&mut self, preset: FillPreset) {
+        self.inner.fill(preset.into());
+    }
+}
@@ -0,0 +1,72 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+// mirrors engine::pattern::Transform for JS, rotations are clockwise
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transform {
+    Identity = 0,
+    Rotate90 = 1,
+    Rotate180 = 2,
+    Rotate270 = 3,
+    FlipHorizontal = 4, // mirrored left to right
+    FlipVertical = 5,   // mirrored top to bottom
+    Transpose = 6,      // mirrored across the main diagonal
+    AntiTranspose = 7,  // mirrored across the other diagonal
+}
+
+impl From<Transform> for engine::pattern::Transform {
+    fn from(transform: Transform) -> engine::pattern::Transform {
+        match transform {
+            Transform::Identity => engine::pattern::Transform::Identity,
+            Transform::Rotate90 => engine::pattern::Transform::Rotate90,
+            Transform::Rotate180 => engine::pattern::Transform::Rotate180,
+            Transform::Rotate270 => engine::pattern::Transform::Rotate270,
+            Transform::FlipHorizontal => engine::pattern::Transform::FlipHorizontal,
+            Transform::FlipVertical => engine::pattern::Transform::FlipVertical,
+            Transform::Transpose => engine::pattern::Transform::Transpose,
+            Transform::AntiTranspose => engine::pattern::Transform::AntiTranspose,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Universe {
+    // ghost an ASCII art pattern ('.' dead, 'O' alive) at (row, col) in render_rgba_aged
+    // without touching the cells, e.g. under the pointer while choosing where to stamp it
+    pub fn set_preview(&mut self, pattern: &str, row: u32, col: u32, transform: Transform) -> Result<(), JsError> {
+        let pattern = engine::pattern::Pattern::from_string(pattern)?;
+        self.inner.set_preview(&pattern, row, col, transform.into());
+        Ok(())
+    }
+
+    pub fn clear_preview(&mut self) {
+        self.inner.clear_preview();
+    }
+
+    pub fn has_preview(&self) -> bool {
+        self.inner.has_preview()
+    }
+
+    // indices of the cells the preview covers, for renderers that draw it themselves
+    pub fn preview_cells(&self) -> Vec<u32> {
+        self.inner.preview_cells().into_iter().map(|idx| idx as u32).collect()
+    }
+
+    // stamp the preview where it is shown and clear it, false if there was none
+    pub fn commit_preview(&mut self) -> bool {
+        self.engine_mut().commit_preview()
+    }
+
+    // color blended over the preview's cells, packed 0xRRGGBBAA - the alpha sets how
+    // see-through it is
+    pub fn set_preview_color(&mut self, color: u32) -> Result<(), JsError> {
+        let palette = engine::age::Palette {
+            preview: color,
+            ..self.inner.palette().clone()
+        };
+        Ok(self.inner.set_palette(palette)?)
+    }
+}
@@ -0,0 +1,83 @@
+use wasm_bindgen::prelude::*;
+
+use crate::continuous::FloatGrid;
+use crate::engine;
+
+// Gray-Scott reaction-diffusion, see engine::reaction_diffusion
+#[wasm_bindgen]
+pub struct ReactionDiffusion {
+    inner: engine::reaction_diffusion::ReactionDiffusion,
+}
+
+#[wasm_bindgen]
+impl ReactionDiffusion {
+    pub fn new(width: u32, height: u32) -> ReactionDiffusion {
+        crate::utils::set_panic_hook();
+        ReactionDiffusion {
+            inner: engine::reaction_diffusion::ReactionDiffusion::new(width, height),
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.inner.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.inner.height()
+    }
+
+    pub fn feed(&self) -> f32 {
+        self.inner.feed()
+    }
+
+    pub fn set_feed(&mut self, feed: f32) {
+        self.inner.set_feed(feed);
+    }
+
+    pub fn kill(&self) -> f32 {
+        self.inner.kill()
+    }
+
+    pub fn set_kill(&mut self, kill: f32) {
+        self.inner.set_kill(kill);
+    }
+
+    pub fn set_diffusion(&mut self, diffusion_u: f32, diffusion_v: f32) {
+        self.inner.set_diffusion(diffusion_u, diffusion_v);
+    }
+
+    // read-only pointers to each chemical's concentrations, view them as Float32Arrays
+    pub fn u_values(&self) -> *const f32 {
+        self.inner.u().values().as_ptr()
+    }
+
+    pub fn v_values(&self) -> *const f32 {
+        self.inner.v().values().as_ptr()
+    }
+
+    // drop a square of chemical v centered on (row, col)
+    pub fn seed(&mut self, row: u32, col: u32, radius: u32) {
+        self.inner.seed(row, col, radius);
+    }
+
+    // reset both chemicals to the initial state (all u, no v)
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    // advance the simulation by one time step
+    pub fn tick(&mut self) {
+        self.inner.tick();
+    }
+}
+
+// rust-only access to the underlying grids
+impl ReactionDiffusion {
+    pub fn u(&self) -> &FloatGrid {
+        self.inner.u()
+    }
+
+    pub fn v(&self) -> &FloatGrid {
+        self.inner.v()
+    }
+}
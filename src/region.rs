@@ -0,0 +1,32 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+// mirrors engine::region::ExportFormat for JS
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Rle = 0,
+    Plaintext = 1, // .cells, 'O' alive and '.' dead
+    Bytes = 2,     // the export_state layout
+}
+
+impl From<ExportFormat> for engine::region::ExportFormat {
+    fn from(format: ExportFormat) -> engine::region::ExportFormat {
+        match format {
+            ExportFormat::Rle => engine::region::ExportFormat::Rle,
+            ExportFormat::Plaintext => engine::region::ExportFormat::Plaintext,
+            ExportFormat::Bytes => engine::region::ExportFormat::Bytes,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Universe {
+    // just the cells between two corners (inclusive, in any order), e.g. an object selected
+    // in a soup - the text formats come as UTF-8, read them with a TextDecoder
+    pub fn export_region(&self, row0: u32, col0: u32, row1: u32, col1: u32, format: ExportFormat) -> Result<Vec<u8>, JsError> {
+        Ok(self.inner.export_region(row0, col0, row1, col1, format.into())?)
+    }
+}
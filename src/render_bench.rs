@@ -0,0 +1,201 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::{Clamped, JsCast};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, ImageData, WebGlProgram, WebGlRenderingContext as Gl};
+
+use crate::{engine, now, Universe};
+
+// a full-canvas quad sampling the RGBA export as a texture, one texel per cell
+const VERTEX_SHADER: &str = "
+attribute vec2 position;
+varying vec2 uv;
+void main() {
+    uv = vec2(position.x * 0.5 + 0.5, 0.5 - position.y * 0.5);
+    gl_Position = vec4(position, 0.0, 1.0);
+}";
+const FRAGMENT_SHADER: &str = "
+precision mediump float;
+varying vec2 uv;
+uniform sampler2D cells;
+void main() {
+    gl_FragColor = texture2D(cells, uv);
+}";
+
+// average milliseconds per frame of each way of drawing the universe, see compare_render_paths
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderTimings {
+    pub per_cell: f64,      // a 2d canvas fillRect per non-dead cell
+    pub image_data: f64,    // render_rgba_aged into an ImageData, put and scaled up with drawImage
+    pub webgl: Option<f64>, // render_rgba_aged uploaded as a texture, undefined without WebGL
+}
+
+#[wasm_bindgen]
+impl Universe {
+    // draw the current universe iterations times through each renderer, on detached canvases
+    // at cell_size pixels per cell, and report the average frame time of each - to pick a
+    // renderer for this grid size on the device at hand. browser only. every frame waits for
+    // its drawing to finish (gl.finish() for WebGL, reading a pixel back for the 2d canvases)
+    // so the GPU work is counted the same way for all three, the cells are left untouched
+    pub fn compare_render_paths(&mut self, iterations: u32, cell_size: u32) -> Result<RenderTimings, JsError> {
+        let iterations = iterations.max(1);
+        let (width, height) = (self.width(), self.height());
+        let cell_size = cell_size.max(1);
+        let (canvas_width, canvas_height) = (width * cell_size, height * cell_size);
+
+        let target = canvas(canvas_width, canvas_height)?;
+        let context = context_2d(&target)?;
+        let per_cell = time(iterations, || {
+            self.draw_per_cell(&context, cell_size as f64);
+            finish_2d(&context)
+        })?;
+
+        let buffer = canvas(width, height)?;
+        let buffer_context = context_2d(&buffer)?;
+        context.set_image_smoothing_enabled(false);
+        let image_data = time(iterations, || {
+            let image = ImageData::new_with_u8_clamped_array_and_sh(Clamped(self.inner.render_rgba_aged()), width, height)?;
+            buffer_context.put_image_data(&image, 0.0, 0.0)?;
+            context.draw_image_with_html_canvas_element_and_dw_and_dh(
+                &buffer,
+                0.0,
+                0.0,
+                canvas_width as f64,
+                canvas_height as f64,
+            )?;
+            finish_2d(&context)
+        })?;
+
+        let gl_canvas = canvas(canvas_width, canvas_height)?;
+        let webgl = match gl_canvas.get_context("webgl").ok().flatten() {
+            Some(gl) => {
+                let gl: Gl = gl.dyn_into().map_err(|_| JsError::new("not a WebGL context"))?;
+                setup_quad(&gl)?;
+                Some(time(iterations, || {
+                    gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+                        Gl::TEXTURE_2D,
+                        0,
+                        Gl::RGBA as i32,
+                        width as i32,
+                        height as i32,
+                        0,
+                        Gl::RGBA,
+                        Gl::UNSIGNED_BYTE,
+                        Some(self.inner.render_rgba_aged()),
+                    )?;
+                    gl.draw_arrays(Gl::TRIANGLE_STRIP, 0, 4);
+                    gl.finish();
+                    Ok(())
+                })?)
+            }
+            None => None,
+        };
+
+        Ok(RenderTimings {
+            per_cell,
+            image_data,
+            webgl,
+        })
+    }
+}
+
+impl Universe {
+    // the classic renderer: clear to the dead color, then one rect per live cell and wall
+    fn draw_per_cell(&self, context: &CanvasRenderingContext2d, cell_size: f64) {
+        let palette = self.inner.palette();
+        context.set_fill_style_str(&css_color(palette.dead));
+        context.fill_rect(0.0, 0.0, self.width() as f64 * cell_size, self.height() as f64 * cell_size);
+
+        // one batch per color, changing fillStyle per cell is far slower
+        let cells = self.inner.get_cells();
+        for (state, color) in [(engine::Cell::Alive, palette.ages[0]), (engine::Cell::Wall, palette.wall)] {
+            context.set_fill_style_str(&css_color(color));
+            for (idx, _) in cells.iter().enumerate().filter(|&(_, &cell)| cell == state) {
+                let (row, col) = (idx as u32 / self.width(), idx as u32 % self.width());
+                context.fill_rect(col as f64 * cell_size, row as f64 * cell_size, cell_size, cell_size);
+            }
+        }
+    }
+}
+
+// average milliseconds per call of frame over iterations calls
+fn time(iterations: u32, mut frame: impl FnMut() -> Result<(), JsValue>) -> Result<f64, JsError> {
+    let start = now();
+    for _ in 0..iterations {
+        frame().map_err(|_| JsError::new("drawing failed"))?;
+    }
+    Ok((now() - start) / iterations as f64)
+}
+
+// a 2d canvas only queues its drawing, reading a pixel back has to wait for all of it
+fn finish_2d(context: &CanvasRenderingContext2d) -> Result<(), JsValue> {
+    context.get_image_data(0.0, 0.0, 1.0, 1.0)?;
+    Ok(())
+}
+
+fn canvas(width: u32, height: u32) -> Result<HtmlCanvasElement, JsError> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsError::new("compare_render_paths needs a browser document"))?;
+    let canvas: HtmlCanvasElement = document
+        .create_element("canvas")
+        .ok()
+        .and_then(|element| element.dyn_into().ok())
+        .ok_or_else(|| JsError::new("could not create a canvas"))?;
+    canvas.set_width(width);
+    canvas.set_height(height);
+    Ok(canvas)
+}
+
+fn context_2d(canvas: &HtmlCanvasElement) -> Result<CanvasRenderingContext2d, JsError> {
+    canvas
+        .get_context("2d")
+        .ok()
+        .flatten()
+        .and_then(|context| context.dyn_into().ok())
+        .ok_or_else(|| JsError::new("no 2d canvas context"))
+}
+
+// compile the shaders, bind the quad and a nearest-neighbor texture for the cells
+fn setup_quad(gl: &Gl) -> Result<(), JsError> {
+    let program = gl.create_program().ok_or_else(|| JsError::new("could not create a WebGL program"))?;
+    for (kind, source) in [(Gl::VERTEX_SHADER, VERTEX_SHADER), (Gl::FRAGMENT_SHADER, FRAGMENT_SHADER)] {
+        let shader = gl.create_shader(kind).ok_or_else(|| JsError::new("could not create a shader"))?;
+        gl.shader_source(&shader, source);
+        gl.compile_shader(&shader);
+        gl.attach_shader(&program, &shader);
+    }
+    gl.link_program(&program);
+    if !linked(gl, &program) {
+        return Err(JsError::new("could not link the WebGL program"));
+    }
+    gl.use_program(Some(&program));
+
+    let quad: [f32; 8] = [-1.0, -1.0, 1.0, -1.0, -1.0, 1.0, 1.0, 1.0];
+    gl.bind_buffer(Gl::ARRAY_BUFFER, gl.create_buffer().as_ref());
+    gl.buffer_data_with_array_buffer_view(Gl::ARRAY_BUFFER, &js_sys::Float32Array::from(&quad[..]), Gl::STATIC_DRAW);
+    let position = gl.get_attrib_location(&program, "position") as u32;
+    gl.enable_vertex_attrib_array(position);
+    gl.vertex_attrib_pointer_with_i32(position, 2, Gl::FLOAT, false, 0, 0);
+
+    // WebGL 1 only samples textures of any size without mipmaps and with clamped edges
+    gl.bind_texture(Gl::TEXTURE_2D, gl.create_texture().as_ref());
+    for (parameter, value) in [
+        (Gl::TEXTURE_MIN_FILTER, Gl::NEAREST),
+        (Gl::TEXTURE_MAG_FILTER, Gl::NEAREST),
+        (Gl::TEXTURE_WRAP_S, Gl::CLAMP_TO_EDGE),
+        (Gl::TEXTURE_WRAP_T, Gl::CLAMP_TO_EDGE),
+    ] {
+        gl.tex_parameteri(Gl::TEXTURE_2D, parameter, value as i32);
+    }
+    Ok(())
+}
+
+fn linked(gl: &Gl, program: &WebGlProgram) -> bool {
+    gl.get_program_parameter(program, Gl::LINK_STATUS).as_bool().unwrap_or(false)
+}
+
+// 0xRRGGBBAA as a CSS color
+fn css_color(color: u32) -> String {
+    let [red, green, blue, alpha] = color.to_be_bytes();
+    format!("rgba({}, {}, {}, {})", red, green, blue, alpha as f64 / 255.0)
+}
@@ -0,0 +1,113 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::hooks::GenerationStats;
+use crate::Universe;
+
+// frame loop helper, see engine::runner: call frame(universe) at the start of every animation
+// frame and end_frame() once it's drawn, and with a target frame rate set the generations per
+// frame follow how long that took. with a generation limit it stops by itself and calls
+// on_complete
+#[wasm_bindgen]
+pub struct Runner {
+    inner: engine::runner::Runner,
+    frame_start: Option<f64>,
+    on_complete: Option<js_sys::Function>,
+}
+
+impl Default for Runner {
+    fn default() -> Runner {
+        Runner::new()
+    }
+}
+
+#[wasm_bindgen]
+impl Runner {
+    pub fn new() -> Runner {
+        crate::utils::set_panic_hook();
+        Runner {
+            inner: engine::runner::Runner::new(),
+            frame_start: None,
+            on_complete: None,
+        }
+    }
+
+    pub fn set_generations_per_frame(&mut self, generations: u32) {
+        self.inner.set_generations_per_frame(generations);
+    }
+
+    // the current speed, also what the UI should show while auto speed is on
+    pub fn generations_per_frame(&self) -> u32 {
+        self.inner.generations_per_frame()
+    }
+
+    pub fn set_max_generations_per_frame(&mut self, max: u32) {
+        self.inner.set_max_generations_per_frame(max);
+    }
+
+    // auto speed: adjust generations per frame to hold this frame rate, 0 turns it off
+    pub fn set_target_fps(&mut self, fps: f64) {
+        self.inner.set_target_fps(Some(fps));
+    }
+
+    // 0 when auto speed is off
+    pub fn target_fps(&self) -> f64 {
+        self.inner.target_fps().unwrap_or(0.0)
+    }
+
+    // average tick + render time of the recent frames in milliseconds, undefined before the
+    // first end_frame
+    pub fn frame_ms(&self) -> Option<f64> {
+        self.inner.frame_ms()
+    }
+
+    // stop once the universe reaches this generation, 0 for no limit - setting it starts a
+    // finished runner over (u64 is a BigInt on the JS side)
+    pub fn set_generation_limit(&mut self, limit: u64) {
+        self.inner.set_generation_limit(Some(limit).filter(|&limit| limit > 0));
+    }
+
+    // 0 when there is no limit
+    pub fn generation_limit(&self) -> u64 {
+        self.inner.generation_limit().unwrap_or(0)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.inner.completed().is_some()
+    }
+
+    // callback(stats) run once when the generation limit is reached, with the final
+    // generation, population, births and deaths. neither the runner nor the universe can be
+    // used from inside it, defer that work with queueMicrotask
+    pub fn on_complete(&mut self, callback: Option<js_sys::Function>) {
+        self.on_complete = callback;
+    }
+
+    // step the universe by one frame's worth of generations, returns how many ran (0 once the
+    // generation limit has been reached)
+    pub fn frame(&mut self, universe: &mut Universe) -> u32 {
+        self.frame_start = Some(crate::now());
+        let was_complete = self.is_complete();
+        let generations = self.inner.frame(universe.engine_mut());
+        universe.check_memory();
+        universe.run_generation_hooks();
+
+        if let (false, Some(stats), Some(callback)) = (was_complete, self.inner.completed(), &self.on_complete) {
+            // a throwing callback is the host's problem, the runner is done either way
+            let _ = callback.call1(&JsValue::NULL, &GenerationStats::from(*stats).into());
+        }
+        generations as u32
+    }
+
+    // the frame started by the last frame() has been rendered
+    pub fn end_frame(&mut self) {
+        if let Some(start) = self.frame_start.take() {
+            self.inner.report_frame_time(crate::now() - start);
+        }
+    }
+
+    // report a frame time measured elsewhere instead of using end_frame, in milliseconds
+    pub fn report_frame_time(&mut self, ms: f64) {
+        self.inner.report_frame_time(ms);
+    }
+}
@@ -0,0 +1,92 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+
+// mirrors engine::sand::Material with the same byte layout, so the material buffer can be
+// handed to JS as-is
+#[wasm_bindgen]
+#[repr(u8)] // one byte per cell, same as Cell, so JS can view the buffer as a Uint8Array
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Material {
+    Empty = 0,
+    Sand = 1,
+    Water = 2,
+    Wall = 3,
+    Fire = 4,
+}
+
+impl From<Material> for engine::sand::Material {
+    fn from(material: Material) -> engine::sand::Material {
+        match material {
+            Material::Empty => engine::sand::Material::Empty,
+            Material::Sand => engine::sand::Material::Sand,
+            Material::Water => engine::sand::Material::Water,
+            Material::Wall => engine::sand::Material::Wall,
+            Material::Fire => engine::sand::Material::Fire,
+        }
+    }
+}
+
+impl From<engine::sand::Material> for Material {
+    fn from(material: engine::sand::Material) -> Material {
+        match material {
+            engine::sand::Material::Empty => Material::Empty,
+            engine::sand::Material::Sand => Material::Sand,
+            engine::sand::Material::Water => Material::Water,
+            engine::sand::Material::Wall => Material::Wall,
+            engine::sand::Material::Fire => Material::Fire,
+        }
+    }
+}
+
+// falling-sand automaton, see engine::sand
+#[wasm_bindgen]
+pub struct SandUniverse {
+    inner: engine::sand::SandUniverse,
+}
+
+#[wasm_bindgen]
+impl SandUniverse {
+    pub fn new(width: u32, height: u32) -> SandUniverse {
+        crate::utils::set_panic_hook();
+        SandUniverse {
+            inner: engine::sand::SandUniverse::new(width, height),
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.inner.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.inner.height()
+    }
+
+    // read-only pointer to the materials, one byte per cell
+    pub fn cells(&self) -> *const Material {
+        // engine::sand::Material and Material share the same single byte representation
+        self.inner.get_cells().as_ptr() as *const Material
+    }
+
+    pub fn get_material(&self, row: u32, col: u32) -> Material {
+        self.inner.get_material(row, col).into()
+    }
+
+    pub fn set_material(&mut self, row: u32, col: u32, material: Material) {
+        self.inner.set_material(row, col, material.into());
+    }
+
+    // fill a disc of the given radius around (row, col), clipped to the grid
+    pub fn paint(&mut self, row: u32, col: u32, radius: u32, material: Material) {
+        self.inner.paint(row, col, radius, material.into());
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    // move every particle once
+    pub fn tick(&mut self) {
+        self.inner.tick(&mut crate::utils::rng());
+    }
+}
@@ -0,0 +1,91 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+#[wasm_bindgen]
+impl Universe {
+    // build a universe from a Uint8Array of width * height cell states (0 dead, 2 a wall,
+    // anything else alive), e.g. loaded from a server
+    pub fn from_cells(width: u32, height: u32, cells: &[u8]) -> Result<Universe, JsError> {
+        crate::utils::set_panic_hook();
+        Ok(Universe::from(engine::Universe::from_cells(width, height, cells)?))
+    }
+
+    // parse ASCII art with '.' for dead and 'O' for alive cells, one row per line,
+    // the dimensions come from the text
+    pub fn from_string(text: &str) -> Result<Universe, JsError> {
+        crate::utils::set_panic_hook();
+        Ok(Universe::from(engine::Universe::from_string(text)?))
+    }
+
+    // same as from_string with custom characters for live and dead cells
+    pub fn from_string_with(text: &str, alive: char, dead: char) -> Result<Universe, JsError> {
+        crate::utils::set_panic_hook();
+        Ok(Universe::from(engine::Universe::from_string_with(text, alive, dead)?))
+    }
+
+    // the whole simulation (cells, ages, rules, wrapping, generation, LifeHistory envelope and
+    // marks, exposure) as one byte array - post the Uint8Array's buffer to a worker as a
+    // transferable and rebuild it there with from_snapshot_bytes
+    pub fn take_snapshot_bytes(&self) -> Vec<u8> {
+        self.inner.take_snapshot_bytes()
+    }
+
+    pub fn from_snapshot_bytes(bytes: &[u8]) -> Result<Universe, JsError> {
+        crate::utils::set_panic_hook();
+        Ok(Universe::from(engine::Universe::from_snapshot_bytes(bytes)?))
+    }
+
+    // an independent copy of the whole simulation (rules, ages, settings and all) to branch
+    // off from, e.g. to try adding a glider without touching the original. the memory change
+    // callback stays with the original
+    pub fn clone_universe(&self) -> Universe {
+        Universe::from(self.inner.clone())
+    }
+
+    // whether both hold the same cells under the same rules and settings, the generation
+    // count aside - see engine::Universe::same_state
+    pub fn equals(&self, other: &Universe) -> bool {
+        self.inner.same_state(&other.inner)
+    }
+
+    // serialize the universe into a flat byte array that JS can store or share
+    // layout: width (u32, little endian), height (u32, little endian), then one byte per cell
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export_state()
+    }
+
+    // replace the universe (dimensions included) with a previously exported state
+    pub fn import_state(&mut self, state: &[u8]) -> Result<(), JsError> {
+        self.inner.import_state(state)?;
+        self.check_memory();
+        Ok(())
+    }
+
+    // same as export_state, but DEFLATE compressed - mostly dead universes shrink a lot
+    #[cfg(feature = "flate2")]
+    pub fn export_state_compressed(&self) -> Vec<u8> {
+        use flate2::write::DeflateEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+        // writing into a Vec can't fail
+        encoder.write_all(&self.export_state()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[cfg(feature = "flate2")]
+    pub fn import_state_compressed(&mut self, compressed: &[u8]) -> Result<(), JsError> {
+        use flate2::read::DeflateDecoder;
+        use std::io::Read;
+
+        let mut state = Vec::new();
+        DeflateDecoder::new(compressed)
+            .read_to_end(&mut state)
+            .map_err(|err| JsError::new(&format!("invalid compressed snapshot: {}", err)))?;
+
+        self.import_state(&state)
+    }
+}
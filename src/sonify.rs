@@ -0,0 +1,36 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+// mirrors engine::sonify::Axis for JS
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    Rows = 0,
+    Columns = 1,
+}
+
+impl From<Axis> for engine::sonify::Axis {
+    fn from(axis: Axis) -> engine::sonify::Axis {
+        match axis {
+            Axis::Rows => engine::sonify::Axis::Rows,
+            Axis::Columns => engine::sonify::Axis::Columns,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Universe {
+    // summarize every tick's births and deaths in this many bands of rows or columns,
+    // 0 turns it off
+    pub fn set_sonification(&mut self, bands: u32, axis: Axis) {
+        self.inner.set_sonification(bands, axis.into());
+    }
+
+    // Float32Array of births per band then deaths per band for the last tick, each 0-1,
+    // ready to map onto WebAudio parameters
+    pub fn sound_data(&self) -> Vec<f32> {
+        self.inner.sound_data().to_vec()
+    }
+}
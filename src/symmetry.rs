@@ -0,0 +1,38 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+
+// mirrors engine::symmetry::Symmetry for JS
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry {
+    None = 0,
+    Horizontal = 1, // mirrored left to right
+    Vertical = 2,   // mirrored top to bottom
+    Rotate180 = 3,  // point symmetric around the center
+    FourFold = 4,   // mirrored both left to right and top to bottom
+}
+
+impl From<Symmetry> for engine::symmetry::Symmetry {
+    fn from(symmetry: Symmetry) -> engine::symmetry::Symmetry {
+        match symmetry {
+            Symmetry::None => engine::symmetry::Symmetry::None,
+            Symmetry::Horizontal => engine::symmetry::Symmetry::Horizontal,
+            Symmetry::Vertical => engine::symmetry::Symmetry::Vertical,
+            Symmetry::Rotate180 => engine::symmetry::Symmetry::Rotate180,
+            Symmetry::FourFold => engine::symmetry::Symmetry::FourFold,
+        }
+    }
+}
+
+impl From<engine::symmetry::Symmetry> for Symmetry {
+    fn from(symmetry: engine::symmetry::Symmetry) -> Symmetry {
+        match symmetry {
+            engine::symmetry::Symmetry::None => Symmetry::None,
+            engine::symmetry::Symmetry::Horizontal => Symmetry::Horizontal,
+            engine::symmetry::Symmetry::Vertical => Symmetry::Vertical,
+            engine::symmetry::Symmetry::Rotate180 => Symmetry::Rotate180,
+            engine::symmetry::Symmetry::FourFold => Symmetry::FourFold,
+        }
+    }
+}
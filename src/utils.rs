@@ -8,3 +8,24 @@ pub fn set_panic_hook() {
     #[cfg(feature = "console_error_panic_hook")]
     console_error_panic_hook::set_once();
 }
+
+// current size of the wasm linear memory in 64KiB pages, always 0 off wasm
+pub fn memory_pages() -> usize {
+    #[cfg(target_arch = "wasm32")]
+    return core::arch::wasm32::memory_size(0);
+    #[cfg(not(target_arch = "wasm32"))]
+    return 0;
+}
+
+// random seed from Math.random, for generators nobody asked to be reproducible
+pub fn seed() -> u64 {
+    // Math.random has 52 bits of precision, two draws cover the whole seed
+    let high = (js_sys::Math::random() * 4294967296.0) as u64;
+    let low = (js_sys::Math::random() * 4294967296.0) as u64;
+    high << 32 | low
+}
+
+// fresh generator for the engine's random fills, seeded from Math.random
+pub fn rng() -> crate::engine::random::Xorshift {
+    crate::engine::random::Xorshift::new(seed())
+}
@@ -0,0 +1,70 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+
+// pan/zoom camera for canvas frontends, see engine::viewport
+#[wasm_bindgen]
+pub struct Viewport {
+    inner: engine::viewport::Viewport,
+}
+
+#[wasm_bindgen]
+impl Viewport {
+    pub fn new() -> Viewport {
+        Viewport {
+            inner: engine::viewport::Viewport::new(),
+        }
+    }
+
+    pub fn x(&self) -> f64 {
+        self.inner.x()
+    }
+
+    pub fn y(&self) -> f64 {
+        self.inner.y()
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.inner.scale()
+    }
+
+    // put cell (y, x) at the top-left of the canvas, scale in pixels per cell
+    pub fn set_viewport(&mut self, x: f64, y: f64, scale: f64) {
+        self.inner.set_viewport(x, y, scale);
+    }
+
+    // drag by (dx, dy) canvas pixels, e.g. from pointermove deltas
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        self.inner.pan(dx, dy);
+    }
+
+    // zoom by factor keeping the cell under canvas point (px, py) in place, e.g. for wheel events
+    pub fn zoom_at(&mut self, px: f64, py: f64, factor: f64) {
+        self.inner.zoom_at(px, py, factor);
+    }
+
+    // [row, col] of the cell under a canvas point, may lie outside the universe
+    pub fn screen_to_cell(&self, px: f64, py: f64) -> Vec<f64> {
+        let (row, col) = self.inner.screen_to_cell(px, py);
+        vec![row as f64, col as f64]
+    }
+
+    // [x, y] canvas position of the top-left corner of a cell
+    pub fn cell_to_screen(&self, row: f64, col: f64) -> Vec<f64> {
+        let (px, py) = self.inner.cell_to_screen(row as i64, col as i64);
+        vec![px, py]
+    }
+}
+
+impl Default for Viewport {
+    fn default() -> Viewport {
+        Viewport::new()
+    }
+}
+
+// rust-only access to the underlying camera
+impl Viewport {
+    pub fn engine(&self) -> &engine::viewport::Viewport {
+        &self.inner
+    }
+}
@@ -0,0 +1,34 @@
+use wasm_bindgen::prelude::*;
+
+use crate::engine;
+use crate::Universe;
+
+#[wasm_bindgen]
+impl Universe {
+    // walls (Cell.Wall, painted with set_cell, paint or draw_line) count as dead neighbors
+    // unless this is on
+    pub fn set_walls_alive(&mut self, alive: bool) {
+        self.inner.set_walls_alive(alive);
+    }
+
+    pub fn walls_alive(&self) -> bool {
+        self.inner.walls_alive()
+    }
+
+    pub fn wall_count(&self) -> usize {
+        self.inner.wall_count()
+    }
+
+    pub fn clear_walls(&mut self) {
+        self.inner.clear_walls();
+    }
+
+    // color of walls in render_rgba_aged, packed 0xRRGGBBAA
+    pub fn set_wall_color(&mut self, color: u32) -> Result<(), JsError> {
+        let palette = engine::age::Palette {
+            wall: color,
+            ..self.inner.palette().clone()
+        };
+        Ok(self.inner.set_palette(palette)?)
+    }
+}
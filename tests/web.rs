@@ -3,7 +3,14 @@
 #![cfg(target_arch = "wasm32")]
 
 use wasm_bindgen_test::*;
-use wasm_game_of_life::Universe;
+use wasm_game_of_life::{Cell, Universe};
+use wasm_game_of_life::buffers::{ElementKind, Layer};
+use wasm_game_of_life::continuous::{ContinuousUniverse, FloatGrid, Kernel};
+use wasm_game_of_life::presets::FillPreset;
+use wasm_game_of_life::reaction_diffusion::ReactionDiffusion;
+use wasm_game_of_life::runner::Runner;
+use wasm_game_of_life::sand::{Material, SandUniverse};
+use wasm_game_of_life::symmetry::Symmetry;
 
 wasm_bindgen_test_configure!(run_in_browser);
 
@@ -12,7 +19,7 @@ wasm_bindgen_test_configure!(run_in_browser);
 // create a starting spaceship pattern
 #[cfg(test)]
 pub fn input_spaceship() -> Universe {
-    let mut universe = Universe::new();
+    let mut universe = Universe::new(6, 6);
     universe.set_width(6);
     universe.set_height(6);
     universe.set_cells(&[(1, 2), (2,3), (3,1), (3,2), (3, 3)]);
@@ -22,7 +29,7 @@ pub fn input_spaceship() -> Universe {
 // check positon of cells after one tick (manually calculated)
 #[cfg(test)]
 pub fn expected_spaceship() -> Universe {
-    let mut universe = Universe::new();
+    let mut universe = Universe::new(6, 6);
     universe.set_width(6);
     universe.set_height(6);
     universe.set_cells(&[(2,1), (2,3), (3,2), (3,3), (4,2)]);
@@ -36,14 +43,297 @@ pub fn test_tick() {
     let mut input_universe = input_spaceship();
     let expected_universe = expected_spaceship();
 
-    input_universe.tick();
-    let input_map = (0..input_universe.get_cells().len()).map(|i| {
-        input_universe.get_cells()[i] == true
-    }).collect::<Vec<bool>>();
+    input_universe.tick(1);
 
-    let expected_map = (0..expected_universe.get_cells().len()).map(|i| {
-        expected_universe.get_cells()[i] == true
-    }).collect::<Vec<bool>>();
+    assert_eq!(input_universe.get_cells(), expected_universe.get_cells());
+}
+
+#[wasm_bindgen_test]
+pub fn test_state_round_trip() {
+    let universe = input_spaceship();
+    let mut restored = Universe::new(3, 3);
+
+    restored.import_state(&universe.export_state()).unwrap();
+
+    assert_eq!(restored.width(), 6);
+    assert_eq!(restored.height(), 6);
+    assert_eq!(restored.get_cells(), universe.get_cells());
+}
+
+#[wasm_bindgen_test]
+pub fn test_wrap_disabled_per_axis() {
+    // a blinker straddling the left/right edge only survives when columns wrap
+    let mut universe = Universe::new(5, 5);
+    universe.die();
+    universe.set_cells(&[(2, 4), (2, 0), (2, 1)]);
+
+    universe.set_wrap(false, true);
+    universe.tick(1);
+
+    assert!(universe.get_cells().iter().all(|&cell| cell == Cell::Dead));
+}
+
+#[wasm_bindgen_test]
+pub fn test_continuous_custom_kernel() {
+    // decays every cell by half each tick
+    struct Decay;
+    impl Kernel for Decay {
+        fn next_value(&self, grid: &FloatGrid, row: u32, column: u32) -> f32 {
+            grid.get_wrapped(row, column, 0, 0) * 0.5
+        }
+    }
+
+    let mut universe = ContinuousUniverse::with_kernel(4, 4, Box::new(Decay));
+    universe.set_value(1, 2, 1.0);
+    universe.tick();
+
+    assert_eq!(universe.get_value(1, 2), 0.5);
+    assert_eq!(universe.get_value(0, 0), 0.0);
+}
+
+#[wasm_bindgen_test]
+pub fn test_lenia_empty_grid_stays_empty() {
+    let mut universe = ContinuousUniverse::new(32, 32);
+    universe.set_lenia(5, 0.15, 0.015, 0.1);
+    universe.tick();
+
+    assert!(universe.grid().values().iter().all(|&value| value == 0.0));
+}
+
+#[wasm_bindgen_test]
+pub fn test_reaction_diffusion_seed_reacts() {
+    let mut simulation = ReactionDiffusion::new(16, 16);
+    simulation.seed(8, 8, 2);
+    simulation.tick();
+
+    let v = simulation.v();
+    // v spreads out from the seed but is not yet present far away
+    assert!(v.values()[v.get_index(8, 11)] > 0.0);
+    assert_eq!(v.values()[v.get_index(0, 0)], 0.0);
+}
+
+#[wasm_bindgen_test]
+pub fn test_sand_falls_onto_wall() {
+    let mut sandbox = SandUniverse::new(3, 5);
+    for col in 0..3 {
+        sandbox.set_material(4, col, Material::Wall);
+    }
+    sandbox.set_material(0, 1, Material::Sand);
+
+    for _ in 0..10 {
+        sandbox.tick();
+    }
+
+    assert_eq!(sandbox.get_material(3, 1), Material::Sand);
+    assert_eq!(sandbox.get_material(4, 1), Material::Wall);
+    assert_eq!(sandbox.get_material(0, 1), Material::Empty);
+}
+
+#[wasm_bindgen_test]
+pub fn test_custom_rule_table() {
+    // every cell copies its left neighbor, shifting the pattern one column right per tick
+    let table: Vec<u8> = (0..512u16).map(|neighborhood| ((neighborhood >> 3) & 1) as u8).collect();
+
+    let mut universe = input_spaceship();
+    universe.set_rule_table(&table).unwrap();
+    universe.tick(1);
+
+    let mut expected = Universe::new(6, 6);
+    expected.die();
+    expected.set_cells(&[(1, 3), (2, 4), (3, 2), (3, 3), (3, 4)]);
+    assert_eq!(universe.get_cells(), expected.get_cells());
+}
+
+#[wasm_bindgen_test]
+pub fn test_neighbor_counts() {
+    let universe = input_spaceship();
+    let counts = universe.neighbor_counts();
+
+    assert_eq!(universe.live_neighbor_count(2, 2), 5);
+    assert_eq!(counts[2 * 6 + 2], 5);
+    assert_eq!(counts.len(), 36);
+}
+
+#[wasm_bindgen_test]
+pub fn test_get_cell() {
+    let universe = input_spaceship();
+
+    assert_eq!(universe.get_cell(1, 2), Some(Cell::Alive));
+    assert_eq!(universe.get_cell(0, 0), Some(Cell::Dead));
+    assert_eq!(universe.get_cell(6, 0), None);
+    assert_eq!(universe.get_cell(0, 6), None);
+}
+
+#[wasm_bindgen_test]
+pub fn test_set_cell_is_idempotent() {
+    let mut universe = input_spaceship();
+    universe.set_cell(0, 0, Cell::Alive);
+    universe.set_cell(0, 0, Cell::Alive);
+    universe.set_cell(1, 2, Cell::Dead);
+
+    assert_eq!(universe.get_cell(0, 0), Some(Cell::Alive));
+    assert_eq!(universe.get_cell(1, 2), Some(Cell::Dead));
+}
+
+#[wasm_bindgen_test]
+pub fn test_set_cells_flat() {
+    let mut universe = Universe::new(6, 6);
+    universe.die();
+    universe.set_cells_flat(&[1, 2, 2, 3, 3, 1, 3, 2, 3, 3], Cell::Alive).unwrap();
 
-    assert_eq!(&input_map, &expected_map);
-}
\ No newline at end of file
+    assert_eq!(universe.get_cells(), input_spaceship().get_cells());
+}
+
+#[wasm_bindgen_test]
+pub fn test_randomize_symmetric() {
+    let mut universe = Universe::new(7, 6);
+    universe.randomize_symmetric(0.5, Symmetry::FourFold);
+
+    for row in 0..6 {
+        for col in 0..7 {
+            let cell = universe.get_cell(row, col);
+            assert_eq!(cell, universe.get_cell(row, 6 - col));
+            assert_eq!(cell, universe.get_cell(5 - row, col));
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+pub fn test_fill_checkerboard() {
+    let mut universe = Universe::new(4, 4);
+    universe.fill(FillPreset::Checkerboard);
+
+    assert_eq!(universe.get_cell(0, 0), Some(Cell::Alive));
+    assert_eq!(universe.get_cell(0, 1), Some(Cell::Dead));
+    assert_eq!(universe.get_cell(3, 3), Some(Cell::Alive));
+}
+
+#[wasm_bindgen_test]
+pub fn test_rule_region() {
+    // a lone cell dies under Conway but survives under B3/S0123 inside the region
+    let mut universe = Universe::new(8, 8);
+    universe.die();
+    universe.set_cells(&[(1, 1), (6, 6)]);
+    universe.add_rule_region(0, 0, 3, 3, "B3/S0123").unwrap();
+    universe.tick(1);
+
+    assert_eq!(universe.get_cell(1, 1), Some(Cell::Alive));
+    assert_eq!(universe.get_cell(6, 6), Some(Cell::Dead));
+    assert!(universe.add_rule_region(0, 0, 1, 1, "B9/S23").is_err());
+}
+
+#[wasm_bindgen_test]
+pub fn test_memory_change_callback() {
+    let mut universe = Universe::new(16, 16);
+    universe.die();
+    universe.set_cells(&[(5, 5), (5, 6), (6, 5), (6, 6)]);
+
+    let callback = js_sys::Function::new_no_args("globalThis.memoryChanges = (globalThis.memoryChanges || 0) + 1");
+    universe.on_memory_change(Some(callback));
+    universe.tick(1);
+    // cropping reallocates the cell buffer
+    universe.crop_to_pattern(1);
+
+    let changes = js_sys::Reflect::get(&js_sys::global(), &"memoryChanges".into()).unwrap();
+    assert!(changes.as_f64().unwrap() >= 1.0);
+}
+
+#[wasm_bindgen_test]
+pub fn test_buffer_descriptor() {
+    let mut universe = Universe::new(8, 4);
+    let descriptor = universe.buffer_descriptor(Layer::Cells);
+
+    assert_eq!(descriptor.byte_offset as usize, universe.cells() as usize);
+    assert_eq!(descriptor.length, 32);
+    assert_eq!(descriptor.kind, ElementKind::Uint8);
+    assert_eq!(descriptor.stride, 8);
+
+    universe.set_track_exposure(true);
+    let heatmap = universe.render_heatmap();
+    let descriptor = universe.buffer_descriptor(Layer::Heatmap);
+    assert_eq!(descriptor.byte_offset as usize, heatmap as usize);
+    assert_eq!(descriptor.length, 32);
+    assert_eq!(descriptor.kind, ElementKind::Float32);
+    assert_eq!(descriptor.stride, 32);
+
+    assert_eq!(universe.buffer_descriptor(Layer::Exposure).length, 32);
+    assert_eq!(universe.buffer_descriptor(Layer::Exposure).kind, ElementKind::Uint32);
+    universe.set_track_exposure(false);
+    assert_eq!(universe.buffer_descriptor(Layer::Exposure).length, 0);
+}
+
+#[wasm_bindgen_test]
+pub fn test_tick_for() {
+    let mut universe = Universe::new(32, 32);
+    assert_eq!(universe.tick_for(0.0), 0);
+    assert!(universe.tick_for(5.0) > 0);
+}
+
+#[cfg(feature = "png")]
+#[wasm_bindgen_test]
+pub fn test_from_png() {
+    // a 3x2 grayscale image, black pixels become live cells
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, 3, 2);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header().unwrap().write_image_data(&[0, 255, 0, 255, 40, 255]).unwrap();
+
+    let universe = Universe::from_png(&bytes, 128).unwrap();
+    assert_eq!((universe.width(), universe.height()), (3, 2));
+    assert_eq!(universe.get_cell(0, 2), Some(Cell::Alive));
+    assert_eq!(universe.get_cell(1, 1), Some(Cell::Alive));
+    assert_eq!(universe.get_cell(1, 0), Some(Cell::Dead));
+}
+
+#[wasm_bindgen_test]
+pub fn test_clone_universe() {
+    let mut universe = input_spaceship();
+    universe.set_wrap(false, true);
+    let mut branch = universe.clone_universe();
+    assert!(branch.equals(&universe));
+
+    branch.toggle_cell(0, 0);
+    assert!(!branch.equals(&universe));
+    branch.toggle_cell(0, 0);
+
+    // settings come along, so both evolve the same way
+    branch.tick(1);
+    universe.tick(1);
+    assert!(branch.equals(&universe));
+    assert!(!branch.wrap_x());
+}
+
+#[wasm_bindgen_test]
+pub fn test_on_generation() {
+    let mut universe = Universe::new(8, 8);
+    let callback = js_sys::Function::new_with_args("stats", "(globalThis.hookGenerations = globalThis.hookGenerations || []).push(Number(stats.generation))");
+    universe.on_generation(2, Some(callback));
+    universe.tick(5);
+
+    let generations = js_sys::Reflect::get(&js_sys::global(), &"hookGenerations".into()).unwrap();
+    let generations: Vec<f64> = js_sys::Array::from(&generations).iter().map(|generation| generation.as_f64().unwrap()).collect();
+    assert_eq!(generations, vec![2.0, 4.0]);
+}
+
+#[wasm_bindgen_test]
+pub fn test_runner_on_complete() {
+    let mut universe = Universe::new(8, 8);
+    let mut runner = Runner::new();
+    runner.set_generations_per_frame(3);
+    runner.set_generation_limit(5);
+    let callback = js_sys::Function::new_with_args("stats", "globalThis.completedAt = Number(stats.generation)");
+    runner.on_complete(Some(callback));
+
+    while runner.frame(&mut universe) > 0 {}
+    assert!(runner.is_complete());
+    let completed = js_sys::Reflect::get(&js_sys::global(), &"completedAt".into()).unwrap();
+    assert_eq!(completed.as_f64(), Some(5.0));
+}
+
+#[wasm_bindgen_test]
+pub fn test_backends_agree() {
+    let mut universe = Universe::new(12, 9);
+    universe.set_wrap(false, true);
+    assert!(universe.assert_backends_agree(10).is_ok());
+}